@@ -0,0 +1,82 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::field_type::{InferenceOptions, Type};
+
+/// A user-defined type (e.g. ISIN codes or internal SKUs), detected alongside the built-in
+/// types when registered with
+/// [`Sniffer::type_detector`](struct.Sniffer.html#method.type_detector). The custom type of a
+/// field is reported in [`FieldMetadata`](metadata/struct.FieldMetadata.html).
+pub trait TypeDetector: fmt::Debug + Send + Sync {
+    /// Name of the type (e.g. `ISIN`).
+    fn name(&self) -> &str;
+
+    /// Whether the (non-empty) value `value` is of this type.
+    fn detect(&self, value: &str) -> bool;
+
+    /// The built-in type that this type ranks ahead of, in the order in which the narrowest
+    /// type of a field is picked (`NULL`, `Boolean`, `Unsigned`, `Signed`, `BigInteger`, `Float`,
+    /// `DateTime`, `Date`, `Text`). A field matching this type is reported as such if its
+    /// built-in type is `priority()` or comes after it; e.g. a detector of numeric SKUs with a
+    /// priority of `Type::Unsigned` wins over `Unsigned` fields.
+    ///
+    /// The priority defaults to `Type::Text`.
+    fn priority(&self) -> Type {
+        Type::Text
+    }
+}
+
+/// Running tally of the values in a single column matched by each registered detector.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct DetectorTally {
+    counts: Vec<usize>,
+}
+
+impl DetectorTally {
+    pub(crate) fn add(&mut self, s: &str, detectors: &[Arc<dyn TypeDetector>]) {
+        self.counts.resize(detectors.len(), 0);
+        for (count, detector) in self.counts.iter_mut().zip(detectors) {
+            if detector.detect(s) {
+                *count += 1;
+            }
+        }
+    }
+
+    /// Number of values matched by each detector, along with its name.
+    pub(crate) fn counts(&self, detectors: &[Arc<dyn TypeDetector>]) -> Vec<(String, usize)> {
+        detectors
+            .iter()
+            .enumerate()
+            .map(|(i, detector)| {
+                (
+                    detector.name().to_string(),
+                    self.counts.get(i).copied().unwrap_or(0),
+                )
+            })
+            .collect()
+    }
+
+    /// The name of the custom type of a column of `n_values` non-empty values whose built-in
+    /// type is `ty`: the highest-priority detector (first registered, among equals) that ranks
+    /// ahead of `ty` and matches at least the type tolerance of the values.
+    pub(crate) fn custom_type(
+        &self,
+        ty: Type,
+        n_values: usize,
+        options: &InferenceOptions,
+    ) -> Option<String> {
+        if n_values == 0 {
+            return None;
+        }
+        let threshold = options.type_tolerance * n_values as f64;
+        options
+            .type_detectors
+            .iter()
+            .zip(&self.counts)
+            .filter(|&(detector, &count)| {
+                count as f64 >= threshold && ty.rank() >= detector.priority().rank()
+            })
+            .min_by_key(|&(detector, _)| detector.priority().rank())
+            .map(|(detector, _)| detector.name().to_string())
+    }
+}
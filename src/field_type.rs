@@ -1,7 +1,25 @@
+use std::borrow::Cow;
 use std::fmt;
+use std::sync::{Arc, LazyLock};
 
-use crate::sniffer::DATE_PREFERENCE;
-use bitflags::bitflags;
+use crate::{
+    detector::{DetectorTally, TypeDetector},
+    geo::{Coordinate, GeometryTally, coordinate_axis},
+    header::{
+        HeaderHint, HeaderRule, HintCondition, is_birthdate_header, is_timestamp_header,
+        matching_rule,
+    },
+    json::JsonTally,
+    list::ListTally,
+    locale::{DateLocale, translate_date},
+    metadata::FieldMetadata,
+    pii::{PiiCategory, PiiMatch, PiiTally, is_pii, redact},
+    semantic::{SemanticTally, SemanticType},
+    sniffer::DATE_PREFERENCE,
+    unit::header_unit,
+};
+use bitflags::{Flags, bitflags};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime};
 use csv::StringRecord;
 use qsv_dateparser::parse_with_preference;
 
@@ -14,19 +32,32 @@ pub enum DatePreference {
     MdyFormat,
 }
 
+/// The order of the day and month in the dates of a field, inferred from its values (e.g.
+/// `25/12/2023` can only be day-first).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateOrder {
+    /// Day before month, dd/mm/yyyy
+    Dmy,
+    /// Month before day, mm/dd/yyyy
+    Mdy,
+    /// Every value fits both orders (e.g. `01/02/2023`)
+    Ambiguous,
+}
+
 bitflags! {
     /// Possible guesses for the field type. Implemented as a bitflag struct (see
     /// [`bitflags`](https://docs.rs/bitflags/)).
-    #[derive(Default, Clone, Copy)]
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
     pub(crate) struct TypeGuesses: u32 {
-        const BOOLEAN   = 0b00000001;
-        const UNSIGNED  = 0b00000010;
-        const SIGNED    = 0b00000100;
-        const FLOAT     = 0b00001000;
-        const DATE      = 0b00010000;
-        const DATETIME  = 0b00100000;
-        const TEXT      = 0b01000000;
-        const NULL      = 0b10000000;
+        const BOOLEAN   = 0b000000001;
+        const UNSIGNED  = 0b000000010;
+        const SIGNED    = 0b000000100;
+        const BIGINT    = 0b000001000;
+        const FLOAT     = 0b000010000;
+        const DATE      = 0b000100000;
+        const DATETIME  = 0b001000000;
+        const TEXT      = 0b010000000;
+        const NULL      = 0b100000000;
     }
 }
 
@@ -50,6 +81,10 @@ impl TypeGuesses {
         else if self.contains(TypeGuesses::SIGNED) {
             Type::Signed
         }
+        // if all values are integer, but some overflow 64 bits, guess big integer
+        else if self.contains(TypeGuesses::BIGINT) {
+            Type::BigInteger
+        }
         // if all values are numeric, but non-integer, guess float
         else if self.contains(TypeGuesses::FLOAT) {
             Type::Float
@@ -76,9 +111,9 @@ impl TypeGuesses {
     }
 }
 
-pub(crate) fn infer_types(s: &str) -> TypeGuesses {
-    if s.is_empty() {
-        // empty fields can be of any type; or rather, of no known type
+pub(crate) fn infer_types(s: &str, options: &InferenceOptions) -> TypeGuesses {
+    if s.trim().is_empty() {
+        // empty (or blank) fields can be of any type; or rather, of no known type
         return TypeGuesses::all();
     }
     let mut guesses = TypeGuesses::default();
@@ -90,10 +125,18 @@ pub(crate) fn infer_types(s: &str) -> TypeGuesses {
     if s.parse::<i64>().is_ok() {
         guesses |= TypeGuesses::SIGNED;
     }
-    if infer_boolean(s) {
+    if is_integer(s) {
+        guesses |= TypeGuesses::BIGINT;
+    }
+    if options.boolean_rules.truth(s).is_some() {
         guesses |= TypeGuesses::BOOLEAN;
     }
-    if s.parse::<f64>().is_ok() {
+    let is_float = if options.strict_numbers {
+        is_decimal(s)
+    } else {
+        s.parse::<f64>().is_ok()
+    };
+    if is_float {
         guesses |= TypeGuesses::FLOAT;
     }
     if let Ok(parsed_date) = parse_with_preference(
@@ -102,7 +145,8 @@ pub(crate) fn infer_types(s: &str) -> TypeGuesses {
             DATE_PREFERENCE.with(|preference| *preference.borrow()),
             DatePreference::DmyFormat
         ),
-    ) {
+    ) && options.date_rules.is_plausible(s, parsed_date.year())
+    {
         // get date in rfc3339 format, if it ends with "T00:00:00+00:00"
         // its a Date type, otherwise, its DateTime.
         if parsed_date.to_rfc3339().ends_with("T00:00:00+00:00") {
@@ -110,22 +154,1096 @@ pub(crate) fn infer_types(s: &str) -> TypeGuesses {
         } else {
             guesses |= TypeGuesses::DATETIME;
         }
+    } else if !is_float && let Some(date) = date_format_guess(s, &options.date_rules) {
+        guesses |= date;
     }
     guesses
 }
 
+// Whether `s` is an integer of any size: an optional sign followed by decimal digits.
+fn is_integer(s: &str) -> bool {
+    let digits = s.strip_prefix(['+', '-']).unwrap_or(s);
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+// Whether `s` follows the strict numeric grammar: an optional sign, then digits with an optional
+// decimal point (with digits on at least one side of it), without an exponent.
+fn is_decimal(s: &str) -> bool {
+    let unsigned = s.strip_prefix(['+', '-']).unwrap_or(s);
+    let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    integer.len() + fraction.len() > 0 && is_digits(integer) && is_digits(fraction)
+}
+
+// Whether `s` is one of the special floating-point tokens (infinity or not-a-number) that
+// `f64::from_str` accepts.
+fn is_special_number(s: &str) -> bool {
+    let unsigned = s.strip_prefix(['+', '-']).unwrap_or(s);
+    ["inf", "infinity", "nan"]
+        .iter()
+        .any(|token| unsigned.eq_ignore_ascii_case(token))
+}
+
+pub(crate) fn infer_record_types(
+    record: &StringRecord,
+    options: &InferenceOptions,
+) -> Vec<TypeGuesses> {
+    record
+        .iter()
+        .map(|field| infer_types(field, options))
+        .collect()
+}
+
+/// The vocabulary of a `Boolean` field, and what it takes for a field to be typed as such, used
+/// when calling `boolean_rules` on `Sniffer`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BooleanRules {
+    /// Values read as true. Values are compared without regard to case.
+    pub true_values: Vec<String>,
+    /// Values read as false. Values are compared without regard to case.
+    pub false_values: Vec<String>,
+    /// Whether a field needs both a true and a false value to be typed `Boolean` (so that e.g.
+    /// a field where every value is `yes` is typed `Text`).
+    pub require_both: bool,
+    /// Whether `1` and `0` are read as true and false. If not, a field of only `0`s and `1`s is
+    /// typed `Unsigned`.
+    pub zero_one: bool,
+}
+
+impl Default for BooleanRules {
+    fn default() -> BooleanRules {
+        BooleanRules {
+            true_values: ["t", "y", "true", "yes"].map(String::from).to_vec(),
+            false_values: ["f", "n", "false", "no"].map(String::from).to_vec(),
+            require_both: false,
+            zero_one: true,
+        }
+    }
+}
+
+impl BooleanRules {
+    /// The truth value of `s`, or `None` if `s` is not in the vocabulary.
+    fn truth(&self, s: &str) -> Option<bool> {
+        if self.zero_one {
+            match s {
+                "1" => return Some(true),
+                "0" => return Some(false),
+                _ => {}
+            }
+        }
+        let lower_s = s.to_lowercase();
+        let matches =
+            |values: &[String]| values.iter().any(|value| value.to_lowercase() == lower_s);
+        if matches(&self.true_values) {
+            Some(true)
+        } else if matches(&self.false_values) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+/// Rules of thumb that the values of a field have to pass for it to be typed `Date` or
+/// `DateTime`, used when calling `date_rules` on `Sniffer`. They keep values like `2020`, `1.5`
+/// or `3-4`, which the date parser happily reads as dates, from being typed as such.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DateRules {
+    /// Earliest plausible year.
+    pub min_year: i32,
+    /// Latest plausible year.
+    pub max_year: i32,
+    /// Minimum number of date and time components (e.g. `2023-01-05` has three: year, month and
+    /// day). Numbers and month names count as components.
+    pub min_components: usize,
+    /// Whether every date of a field has to use the same separator between its components
+    /// (e.g. `2023-01-05` and `2023/01/06` do not).
+    pub consistent_separator: bool,
+}
+
+impl Default for DateRules {
+    fn default() -> DateRules {
+        DateRules {
+            min_year: 1800,
+            max_year: 2200,
+            min_components: 3,
+            consistent_separator: true,
+        }
+    }
+}
+
+impl DateRules {
+    /// Whether the date value `s`, whose year is `year`, passes the year range and component
+    /// count rules.
+    fn is_plausible(&self, s: &str, year: i32) -> bool {
+        (self.min_year..=self.max_year).contains(&year) && date_components(s) >= self.min_components
+    }
+}
+
+// Number of date and time components in `s`: its runs of digits and its English month names.
+fn date_components(s: &str) -> usize {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+    let mut n_components = 0;
+    let mut rest = s;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_alphanumeric()) {
+        rest = &rest[start..];
+        let is_digits = rest.starts_with(|c: char| c.is_ascii_digit());
+        let end = rest
+            .find(|c: char| {
+                if is_digits {
+                    !c.is_ascii_digit()
+                } else {
+                    !c.is_ascii_alphabetic()
+                }
+            })
+            .unwrap_or(rest.len());
+        let run = &rest[..end];
+        if is_digits
+            || MONTHS.iter().any(|month| {
+                run.get(..3)
+                    .is_some_and(|abbr| abbr.eq_ignore_ascii_case(month))
+            })
+        {
+            n_components += 1;
+        }
+        rest = &rest[end..];
+    }
+    n_components
+}
+
+// The first separator between the components of the date value `s` (e.g. `-` in `2023-01-05`).
+fn date_separator(s: &str) -> Option<char> {
+    s.trim().chars().find(|c| !c.is_alphanumeric())
+}
+
+// Date layouts tried when inferring the format of a date field, in order of preference. Where a
+// value fits several of them (e.g. `01/02/03`), the first one is reported.
+const DATE_LAYOUTS: [&str; 30] = [
+    "%Y-%m-%d",
+    "%Y/%m/%d",
+    "%Y.%m.%d",
+    "%m/%d/%y",
+    "%m/%d/%Y",
+    "%d/%m/%y",
+    "%d/%m/%Y",
+    "%m-%d-%y",
+    "%m-%d-%Y",
+    "%d-%m-%y",
+    "%d-%m-%Y",
+    "%d.%m.%y",
+    "%d.%m.%Y",
+    "%b %d, %Y",
+    "%B %d, %Y",
+    "%b %d %Y",
+    "%B %d %Y",
+    "%d %b %Y",
+    "%d %B %Y",
+    "%d. %b %Y",
+    "%d. %B %Y",
+    "%d-%b-%y",
+    "%d-%b-%Y",
+    "%Y-%b-%d",
+    "%a, %d %b %Y",
+    "%A, %d %B %Y",
+    "%a, %b %d, %Y",
+    "%A, %B %d, %Y",
+    "%a, %d. %b %Y",
+    "%A, %d. %B %Y",
+];
+// Time layouts appended to the date layouts for datetime formats.
+const TIME_LAYOUTS: [&str; 5] = [
+    "%H:%M:%S",
+    "%H:%M",
+    "%H:%M:%S%.f",
+    "%I:%M:%S %p",
+    "%I:%M %p",
+];
+// Time zone suffixes appended to the datetime formats.
+const ZONE_LAYOUTS: [&str; 5] = ["", "Z", "%z", " %z", " %Z"];
+
+/// The strftime-style formats tried when inferring the date format of a field, in order of
+/// preference: all date layouts, followed by all datetime layouts.
+static DATE_FORMATS: LazyLock<Vec<String>> = LazyLock::new(|| {
+    let mut formats: Vec<String> = DATE_LAYOUTS.iter().map(|date| date.to_string()).collect();
+    for date in DATE_LAYOUTS {
+        let separators: &[&str] = if date == "%Y-%m-%d" {
+            &[" ", "T"]
+        } else {
+            &[" "]
+        };
+        for sep in separators {
+            for time in TIME_LAYOUTS {
+                for zone in ZONE_LAYOUTS {
+                    formats.push(format!("{date}{sep}{time}{zone}"));
+                }
+            }
+        }
+    }
+    formats
+});
+
+#[inline]
+fn is_datetime_format(format: &str) -> bool {
+    format.contains("%H") || format.contains("%I")
+}
+
+// The year of `s` when parsed using the strftime-style `format`, if `s` fits the format.
+fn date_format_year(s: &str, format: &str) -> Option<i32> {
+    if format.ends_with("%z") {
+        DateTime::parse_from_str(s, format)
+            .ok()
+            .map(|datetime| datetime.year())
+    } else if is_datetime_format(format) {
+        // chrono skips over any non-whitespace characters for %Z, so make sure it looks like
+        // a time zone abbreviation (e.g. UTC, EST, CEST)
+        if format.ends_with("%Z")
+            && !s.rsplit(' ').next().is_some_and(|zone| {
+                (2..=5).contains(&zone.len()) && zone.bytes().all(|b| b.is_ascii_uppercase())
+            })
+        {
+            return None;
+        }
+        NaiveDateTime::parse_from_str(s, format)
+            .ok()
+            .map(|datetime| datetime.year())
+    } else {
+        NaiveDate::parse_from_str(s, format)
+            .ok()
+            .map(|date| date.year())
+    }
+}
+
 #[inline]
-fn infer_boolean(s: &str) -> bool {
-    let first5chars: String = s.chars().take(5).collect();
-    let lower_s = first5chars.to_lowercase();
-    matches!(
-        lower_s.as_str(),
-        "0" | "1" | "t" | "f" | "y" | "n" | "true" | "false" | "yes" | "no"
-    )
+fn fits_date_format(s: &str, format: &str) -> bool {
+    date_format_year(s, format).is_some()
+}
+
+// The date type of `s` if it fits one of the date formats, for the dates the date parser does not
+// read (e.g. `05.01.2023`), as long as its year and components are plausible.
+fn date_format_guess(s: &str, rules: &DateRules) -> Option<TypeGuesses> {
+    if date_components(s) < rules.min_components {
+        return None;
+    }
+    let (format, year) = DATE_FORMATS
+        .iter()
+        .find_map(|format| Some((format, date_format_year(s, format)?)))?;
+    rules.is_plausible(s, year).then(|| {
+        if is_datetime_format(format) {
+            TypeGuesses::DATETIME
+        } else {
+            TypeGuesses::DATE
+        }
+    })
+}
+
+/// Whether `s` is a date (without a time) in any of the date layouts.
+pub(crate) fn fits_date_layout(s: &str) -> bool {
+    DATE_LAYOUTS
+        .iter()
+        .any(|layout| fits_date_format(s, layout))
+}
+
+/// Running guess of the date format of a field: the formats (indices into `DATE_FORMATS`) that
+/// fit every date value seen so far.
+#[derive(Debug, Default, Clone)]
+struct DateFormatGuesses {
+    candidates: Option<Vec<usize>>,
 }
 
-pub(crate) fn infer_record_types(record: &StringRecord) -> Vec<TypeGuesses> {
-    record.iter().map(infer_types).collect()
+impl DateFormatGuesses {
+    /// Narrow down the candidate date formats with the value `s`, if its type guesses `guesses`
+    /// make it a date. The first date is tried against every format, and the dates after it only
+    /// against the formats that fit the dates before.
+    fn add(&mut self, s: &str, guesses: TypeGuesses, rules: &DateRules) {
+        // the date parser takes any number for a unix timestamp, those are not date formats
+        if guesses.contains(TypeGuesses::FLOAT)
+            || !guesses.intersects(TypeGuesses::DATE | TypeGuesses::DATETIME)
+        {
+            return;
+        }
+        let fits: Vec<usize> = match self.candidates.take() {
+            Some(candidates) => candidates
+                .into_iter()
+                .filter(|&i| fits_date_format(s, &DATE_FORMATS[i]))
+                .collect(),
+            None => (0..DATE_FORMATS.len())
+                .filter(|&i| fits_date_format(s, &DATE_FORMATS[i]))
+                .collect(),
+        };
+        let is_plausible = fits.first().is_some_and(|&i| {
+            date_format_year(s, &DATE_FORMATS[i]).is_some_and(|year| rules.is_plausible(s, year))
+        });
+        // a date that doesn't fit leaves no single format for this field
+        self.candidates = Some(if is_plausible { fits } else { vec![] });
+    }
+
+    /// The preferred format among the formats that fit every date value seen, if any. When the
+    /// order of days and months is ambiguous, the date preference breaks the tie.
+    fn format(&self) -> Option<&'static str> {
+        let candidates = self.candidates.as_ref()?;
+        let preferred_order = match DATE_PREFERENCE.with(|preference| *preference.borrow()) {
+            DatePreference::DmyFormat => DateOrder::Dmy,
+            DatePreference::MdyFormat => DateOrder::Mdy,
+        };
+        candidates
+            .iter()
+            .find(|&&i| {
+                date_format_order(&DATE_FORMATS[i]).is_none_or(|order| order == preferred_order)
+            })
+            .or_else(|| candidates.first())
+            .map(|&i| DATE_FORMATS[i].as_str())
+    }
+
+    /// The order of days and months implied by the formats that fit every date value seen, if
+    /// any of them has numeric days and months before the year.
+    fn order(&self) -> Option<DateOrder> {
+        let candidates = self.candidates.as_ref()?;
+        let (dmy, mdy) = candidates.iter().fold((false, false), |(dmy, mdy), &i| {
+            match date_format_order(&DATE_FORMATS[i]) {
+                Some(DateOrder::Dmy) => (true, mdy),
+                Some(DateOrder::Mdy) => (dmy, true),
+                _ => (dmy, mdy),
+            }
+        });
+        match (dmy, mdy) {
+            (true, true) => Some(DateOrder::Ambiguous),
+            (true, false) => Some(DateOrder::Dmy),
+            (false, true) => Some(DateOrder::Mdy),
+            (false, false) => None,
+        }
+    }
+}
+
+// The order of the numeric day and month of a format (e.g. `%d/%m/%Y` is `DateOrder::Dmy`), or
+// `None` if its year comes first or its month is a name.
+fn date_format_order(format: &str) -> Option<DateOrder> {
+    match (
+        format.find("%d"),
+        format.find("%m"),
+        format.find("%y").or(format.find("%Y")),
+    ) {
+        (Some(day), Some(month), Some(year)) if day < year && month < year => {
+            Some(if day < month {
+                DateOrder::Dmy
+            } else {
+                DateOrder::Mdy
+            })
+        }
+        _ => None,
+    }
+}
+
+/// The narrowest integer width that fits every value of an integer field (e.g. a `Signed` field
+/// of values between -100 and 100 fits in 8 bits, as an `i8`). `BigInteger` fields fit in 128
+/// bits at best.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IntegerWidth {
+    /// 8 bits (`u8` or `i8`)
+    Bits8,
+    /// 16 bits (`u16` or `i16`)
+    Bits16,
+    /// 32 bits (`u32` or `i32`)
+    Bits32,
+    /// 64 bits (`u64` or `i64`)
+    Bits64,
+    /// 128 bits (`u128` or `i128`)
+    Bits128,
+}
+
+impl IntegerWidth {
+    const ALL: [IntegerWidth; 5] = [
+        IntegerWidth::Bits8,
+        IntegerWidth::Bits16,
+        IntegerWidth::Bits32,
+        IntegerWidth::Bits64,
+        IntegerWidth::Bits128,
+    ];
+
+    /// Number of bits of this width.
+    pub const fn bits(self) -> u32 {
+        match self {
+            IntegerWidth::Bits8 => 8,
+            IntegerWidth::Bits16 => 16,
+            IntegerWidth::Bits32 => 32,
+            IntegerWidth::Bits64 => 64,
+            IntegerWidth::Bits128 => 128,
+        }
+    }
+
+    // The narrowest width whose unsigned integers fit `max`.
+    fn unsigned(max: u128) -> IntegerWidth {
+        // safety: every u128 fits in 128 bits
+        *IntegerWidth::ALL
+            .iter()
+            .find(|width| max <= u128::MAX >> (128 - width.bits()))
+            .unwrap()
+    }
+
+    // The narrowest width whose signed integers fit both `min` and `max`, if any does.
+    fn signed(min: i128, max: u128) -> Option<IntegerWidth> {
+        IntegerWidth::ALL.iter().copied().find(|width| {
+            let upper = i128::MAX >> (128 - width.bits());
+            min >= -upper - 1 && max <= upper.unsigned_abs()
+        })
+    }
+}
+
+/// The size of the numbers of a numeric field, for sizing a SQL `NUMERIC(precision, scale)`
+/// column.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NumericSize {
+    /// Maximum number of digits before the decimal point (not counting leading zeros).
+    pub integer_digits: usize,
+    /// Maximum number of digits after the decimal point (counting trailing zeros).
+    pub fraction_digits: usize,
+    /// Whether any number is written with an exponent (e.g. `1.5e10`), in which case the digit
+    /// counts only cover the digits before the exponent.
+    pub has_exponent: bool,
+}
+
+impl NumericSize {
+    /// Total number of significant digits needed to hold every number exactly.
+    pub const fn precision(&self) -> usize {
+        self.integer_digits + self.fraction_digits
+    }
+
+    /// Number of digits needed after the decimal point.
+    pub const fn scale(&self) -> usize {
+        self.fraction_digits
+    }
+
+    /// Whether every number is an exact decimal (written without an exponent), and therefore
+    /// fits `NUMERIC(precision, scale)` without loss.
+    pub const fn is_exact_decimal(&self) -> bool {
+        !self.has_exponent
+    }
+
+    // The size of the decimal number `s`, or `None` if `s` is not written with digits (e.g.
+    // `inf`).
+    fn of(s: &str) -> Option<NumericSize> {
+        let unsigned = s.strip_prefix(['+', '-']).unwrap_or(s);
+        let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+            Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+            None => (unsigned, None),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if integer.len() + fraction.len() == 0 || !is_digits(integer) || !is_digits(fraction) {
+            return None;
+        }
+        Some(NumericSize {
+            integer_digits: integer.trim_start_matches('0').len(),
+            fraction_digits: fraction.len(),
+            has_exponent: exponent.is_some(),
+        })
+    }
+
+    fn merge(&mut self, other: NumericSize) {
+        self.integer_digits = self.integer_digits.max(other.integer_digits);
+        self.fraction_digits = self.fraction_digits.max(other.fraction_digits);
+        self.has_exponent |= other.has_exponent;
+    }
+}
+
+/// The length of the longest value of a text field, for sizing a SQL `VARCHAR(n)` column.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TextLength {
+    /// Maximum length in bytes (of the UTF-8 encoded value).
+    pub max_bytes: usize,
+    /// Maximum length in characters (Unicode scalar values).
+    pub max_chars: usize,
+}
+
+/// The unit of a field holding Unix timestamps (the time elapsed since 1970-01-01 00:00:00 UTC).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpochUnit {
+    /// Seconds since the epoch
+    Seconds,
+    /// Milliseconds since the epoch
+    Milliseconds,
+    /// Microseconds since the epoch
+    Microseconds,
+}
+
+impl EpochUnit {
+    // Timestamps are only plausible between 1980-01-01 and 2100-01-01 (in seconds).
+    const MIN_SECONDS: f64 = 315_532_800.0;
+    const MAX_SECONDS: f64 = 4_102_444_800.0;
+
+    /// The unit in which `value` is a plausible Unix timestamp, if any. The plausible ranges of
+    /// the units do not overlap, so there is at most one.
+    fn of(value: f64) -> Option<EpochUnit> {
+        [
+            (EpochUnit::Seconds, 1.0),
+            (EpochUnit::Milliseconds, 1e3),
+            (EpochUnit::Microseconds, 1e6),
+        ]
+        .into_iter()
+        .find(|(_, scale)| {
+            (EpochUnit::MIN_SECONDS * scale..EpochUnit::MAX_SECONDS * scale).contains(&value)
+        })
+        .map(|(unit, _)| unit)
+    }
+}
+
+/// How a datetime value specifies its time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZoneNotation {
+    /// No time zone (e.g. `2023-01-05 08:15:00`)
+    Naive,
+    /// `Z` suffix (e.g. `2023-01-05T08:15:00Z`)
+    Z,
+    /// Numeric UTC offset (e.g. `2023-01-05T08:15:00+02:00`)
+    Offset,
+    /// Time zone abbreviation (e.g. `2023-01-05 08:15:00 EST`)
+    Name,
+}
+
+/// Time zone details of a `DateTime` field. A field whose values all are `ZoneNotation::Naive`
+/// maps to a SQL `timestamp`, while a zone-aware field maps to a `timestamptz`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateTimeZone {
+    /// Notations of the time zones in the values, in order of first appearance.
+    pub notations: Vec<ZoneNotation>,
+    /// UTC offset (in seconds east of UTC) shared by every value, if every value is zone-aware
+    /// and they all have the same offset.
+    pub offset: Option<i32>,
+}
+
+impl DateTimeZone {
+    /// Whether no value of the field has a time zone.
+    pub fn is_naive(&self) -> bool {
+        self.notations
+            .iter()
+            .all(|notation| *notation == ZoneNotation::Naive)
+    }
+
+    /// Whether every value of the field has a time zone.
+    pub fn is_aware(&self) -> bool {
+        !self.notations.contains(&ZoneNotation::Naive)
+    }
+}
+
+// UTC offsets (in hours) of common time zone abbreviations.
+const ZONE_ABBREVIATIONS: [(&str, i32); 28] = [
+    ("UTC", 0),
+    ("UT", 0),
+    ("GMT", 0),
+    ("WET", 0),
+    ("WEST", 1),
+    ("BST", 1),
+    ("CET", 1),
+    ("CEST", 2),
+    ("EET", 2),
+    ("EEST", 3),
+    ("MSK", 3),
+    ("JST", 9),
+    ("KST", 9),
+    ("AWST", 8),
+    ("AEST", 10),
+    ("AEDT", 11),
+    ("NZST", 12),
+    ("HST", -10),
+    ("AKST", -9),
+    ("AKDT", -8),
+    ("PST", -8),
+    ("PDT", -7),
+    ("MST", -7),
+    ("MDT", -6),
+    ("CST", -6),
+    ("CDT", -5),
+    ("EST", -5),
+    ("EDT", -4),
+];
+
+/// The time zone notation of the datetime value `s`, along with its UTC offset (in seconds
+/// east of UTC) if known.
+fn datetime_zone(s: &str) -> (ZoneNotation, Option<i32>) {
+    let bytes = s.as_bytes();
+    if bytes.len() > 1 && bytes[bytes.len() - 1] == b'Z' && bytes[bytes.len() - 2].is_ascii_digit()
+    {
+        return (ZoneNotation::Z, Some(0));
+    }
+    // numeric offsets: +hh, +hhmm or +hh:mm, right after the time
+    let digits: Vec<u8> = bytes
+        .iter()
+        .rev()
+        .take_while(|b| b.is_ascii_digit() || **b == b':')
+        .copied()
+        .collect();
+    let sign_at = bytes.len() - digits.len();
+    if sign_at > 1 && matches!(bytes[sign_at - 1], b'+' | b'-') {
+        let hhmm: Vec<i32> = digits
+            .iter()
+            .rev()
+            .filter(|b| b.is_ascii_digit())
+            .map(|b| i32::from(b - b'0'))
+            .collect();
+        let before_sign = bytes[sign_at - 2];
+        if matches!(hhmm.len(), 2 | 4) && (before_sign.is_ascii_digit() || before_sign == b' ') {
+            let hours = hhmm[0] * 10 + hhmm[1];
+            let minutes = if hhmm.len() == 4 {
+                hhmm[2] * 10 + hhmm[3]
+            } else {
+                0
+            };
+            let sign = if bytes[sign_at - 1] == b'-' { -1 } else { 1 };
+            return (
+                ZoneNotation::Offset,
+                Some(sign * (hours * 3600 + minutes * 60)),
+            );
+        }
+    }
+    if let Some((_, zone)) = s.rsplit_once(' ') {
+        if (2..=5).contains(&zone.len())
+            && zone.bytes().all(|b| b.is_ascii_uppercase())
+            && zone != "AM"
+            && zone != "PM"
+        {
+            let offset = ZONE_ABBREVIATIONS
+                .iter()
+                .find(|(name, _)| *name == zone)
+                .map(|(_, hours)| hours * 3600);
+            return (ZoneNotation::Name, offset);
+        }
+    }
+    (ZoneNotation::Naive, None)
+}
+
+/// Running tally of the time zones of the datetime values of a field.
+#[derive(Debug, Default, Clone)]
+struct ZoneTally {
+    notations: Vec<ZoneNotation>,
+    // `None` until a zone-aware value is seen, then `Some(None)` once offsets differ (or are
+    // unknown)
+    offset: Option<Option<i32>>,
+}
+
+impl ZoneTally {
+    fn add(&mut self, s: &str) {
+        let (notation, offset) = datetime_zone(s);
+        if !self.notations.contains(&notation) {
+            self.notations.push(notation);
+        }
+        if notation != ZoneNotation::Naive {
+            let shared = self.offset.get_or_insert(offset);
+            if *shared != offset {
+                *shared = None;
+            }
+        }
+    }
+
+    fn zone(&self) -> Option<DateTimeZone> {
+        if self.notations.is_empty() {
+            return None;
+        }
+        let is_aware = !self.notations.contains(&ZoneNotation::Naive);
+        Some(DateTimeZone {
+            notations: self.notations.clone(),
+            offset: self.offset.flatten().filter(|_| is_aware),
+        })
+    }
+}
+
+/// Options set on `Sniffer` that affect how the values of a field are typed.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct InferenceOptions {
+    pub(crate) type_tolerance: f64,
+    pub(crate) boolean_rules: BooleanRules,
+    pub(crate) date_locales: Vec<DateLocale>,
+    pub(crate) date_rules: DateRules,
+    pub(crate) strict_numbers: bool,
+    pub(crate) redact_pii: bool,
+    pub(crate) type_detectors: Vec<Arc<dyn TypeDetector>>,
+    pub(crate) header_rules: Vec<HeaderRule>,
+}
+
+/// Number of flags in `TypeGuesses`.
+const N_GUESSES: usize = TypeGuesses::FLAGS.len();
+/// Maximum number of distinct outlier examples kept per type guess.
+const MAX_OUTLIER_EXAMPLES: usize = 5;
+
+/// Running tally of the type guesses for every value in a single column. Unlike intersecting the
+/// guesses of each value, this keeps track of how many values matched each guess, so that a
+/// column can still be typed when a small proportion of its values are outliers.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct TypeTally {
+    n_values: usize,
+    n_empty: usize,
+    counts: [usize; N_GUESSES],
+    outliers: [Vec<String>; N_GUESSES],
+    date_formats: DateFormatGuesses,
+    // the locales needed to read every localized date seen so far
+    date_locales: Option<Vec<DateLocale>>,
+    // smallest and largest numeric values seen so far
+    numeric_range: Option<(f64, f64)>,
+    // smallest and largest integer values (up to 128 bits) seen so far, with negative values
+    // counting as zero towards the largest
+    integer_range: Option<(i128, u128)>,
+    // whether any integer seen so far overflows 128 bits
+    integer_overflow: bool,
+    numeric_size: Option<NumericSize>,
+    text_length: TextLength,
+    // length in bytes of the shortest value seen so far
+    min_bytes: Option<usize>,
+    // distinct special floating-point tokens seen so far
+    special_numbers: Vec<String>,
+    semantics: SemanticTally,
+    pii: PiiTally,
+    json: JsonTally,
+    lists: ListTally,
+    geometries: GeometryTally,
+    detectors: DetectorTally,
+    zones: ZoneTally,
+    // separator of the first date seen, and whether every date since used the same separator
+    date_separator: Option<Option<char>>,
+    mixed_date_separators: bool,
+    // whether a false and a true value were seen
+    truth_values: [bool; 2],
+}
+
+impl TypeTally {
+    pub(crate) fn add(&mut self, s: &str, options: &InferenceOptions) {
+        if s.trim().is_empty() {
+            self.n_empty += 1;
+            return;
+        }
+        self.n_values += 1;
+        self.text_length.max_bytes = self.text_length.max_bytes.max(s.len());
+        self.text_length.max_chars = self.text_length.max_chars.max(s.chars().count());
+        self.min_bytes = Some(self.min_bytes.map_or(s.len(), |min| min.min(s.len())));
+        self.semantics.add(s);
+        self.json.add(s);
+        self.geometries.add(s);
+        self.detectors.add(s, &options.type_detectors);
+        let mut guesses = infer_types(s, options);
+        let dates = TypeGuesses::DATE | TypeGuesses::DATETIME;
+        let mut date_value = Cow::Borrowed(s);
+        let mut locales = None;
+        if !guesses.intersects(dates) && !options.date_locales.is_empty() {
+            if let Some((translated, matched)) = translate_date(s, &options.date_locales) {
+                guesses |= infer_types(&translated, options) & dates;
+                date_value = Cow::Owned(translated);
+                locales = Some(matched);
+            }
+        }
+        self.date_formats
+            .add(&date_value, guesses, &options.date_rules);
+        if options.date_rules.consistent_separator
+            && guesses.intersects(dates)
+            && !guesses.contains(TypeGuesses::FLOAT)
+        {
+            let separator = date_separator(&date_value);
+            if *self.date_separator.get_or_insert(separator) != separator {
+                self.mixed_date_separators = true;
+            }
+        }
+        if let Some(matched) = locales.filter(|_| guesses.intersects(dates)) {
+            let candidates = self.date_locales.get_or_insert(matched.clone());
+            candidates.retain(|locale| matched.contains(locale));
+        }
+        if guesses.contains(TypeGuesses::DATETIME) && !guesses.contains(TypeGuesses::FLOAT) {
+            self.zones.add(&date_value);
+        }
+        if let Some(truth) = options.boolean_rules.truth(s) {
+            self.truth_values[usize::from(truth)] = true;
+        }
+        if let Some(value) = parse_integer(s) {
+            let (min, max) = self.integer_range.get_or_insert(value);
+            *min = (*min).min(value.0);
+            *max = (*max).max(value.1);
+        } else if guesses.contains(TypeGuesses::BIGINT) {
+            self.integer_overflow = true;
+        }
+        if is_special_number(s) && !self.special_numbers.iter().any(|token| token == s) {
+            self.special_numbers.push(s.to_string());
+        }
+        if guesses.intersects(TypeGuesses::FLOAT | TypeGuesses::BIGINT)
+            && let Some(size) = NumericSize::of(s)
+        {
+            self.numeric_size.get_or_insert_default().merge(size);
+        }
+        if guesses.contains(TypeGuesses::FLOAT) {
+            if let Ok(value) = s.parse::<f64>() {
+                let (min, max) = self.numeric_range.get_or_insert((value, value));
+                *min = min.min(value);
+                *max = max.max(value);
+            }
+        }
+        self.pii.add(s, guesses);
+        self.lists.add(s, guesses, options);
+        let example = if options.redact_pii && is_pii(s, guesses) {
+            Cow::Owned(redact(s))
+        } else {
+            Cow::Borrowed(s)
+        };
+        for (i, flag) in TypeGuesses::FLAGS.iter().enumerate() {
+            if guesses.contains(*flag.value()) {
+                self.counts[i] += 1;
+            } else if self.outliers[i].len() < MAX_OUTLIER_EXAMPLES
+                && !self.outliers[i].iter().any(|outlier| *outlier == example)
+            {
+                self.outliers[i].push(example.to_string());
+            }
+        }
+    }
+
+    /// The type guesses matched by at least the type tolerance (a proportion between 0.0 and 1.0)
+    /// of the non-empty values in this column, when only `Text` fits all of them (otherwise, the
+    /// guesses that fit all of them). A column without any non-empty values matches every guess
+    /// (and is therefore `Type::NULL`). A column whose dates do not all use the same
+    /// separator (when `DateRules::consistent_separator` is set) matches no date guess, and a
+    /// column without both truth values (when `BooleanRules::require_both` is set) does not
+    /// match the boolean guess.
+    pub(crate) fn guesses(&self, options: &InferenceOptions) -> TypeGuesses {
+        if self.n_values == 0 {
+            return TypeGuesses::all();
+        }
+        let guesses_over = |threshold: f64| {
+            let mut guesses = TypeGuesses::FLAGS
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| self.counts[*i] as f64 >= threshold)
+                .fold(TypeGuesses::empty(), |acc, (_, flag)| acc | *flag.value());
+            if self.mixed_date_separators {
+                guesses -= TypeGuesses::DATE | TypeGuesses::DATETIME;
+            }
+            if options.boolean_rules.require_both && self.truth_values != [true; 2] {
+                guesses -= TypeGuesses::BOOLEAN;
+            }
+            guesses
+        };
+        // a narrower type that most values fit does not beat a wider one that all of them fit
+        // (e.g. `Boolean` for counts that are mostly 0 or 1)
+        let guesses = guesses_over(self.n_values as f64);
+        if guesses != TypeGuesses::TEXT {
+            return guesses;
+        }
+        guesses_over(options.type_tolerance * self.n_values as f64)
+    }
+
+    /// Whether at least the type tolerance of the non-empty values in this column fit `ty` (which
+    /// is always the case for a column without any non-empty value).
+    fn fits(&self, ty: Type, options: &InferenceOptions) -> bool {
+        match type_guess(ty) {
+            Some(guess) if self.n_values > 0 => {
+                // safety: every flag returned by `type_guess` is a flag of `TypeGuesses`
+                let i = TypeGuesses::FLAGS
+                    .iter()
+                    .position(|flag| *flag.value() == guess)
+                    .unwrap();
+                self.counts[i] as f64 >= options.type_tolerance * self.n_values as f64
+            }
+            _ => true,
+        }
+    }
+
+    /// The smallest and largest numbers among the values of this column, if any are numbers.
+    pub(crate) fn numeric_range(&self) -> Option<(f64, f64)> {
+        self.numeric_range
+    }
+
+    /// The influence of the first header rule matching the header `name` on this column, whose
+    /// type inferred from its values is `ty`.
+    pub(crate) fn header_hint(
+        &self,
+        name: &str,
+        ty: Type,
+        options: &InferenceOptions,
+    ) -> Option<HeaderHint> {
+        let rule = matching_rule(name, &options.header_rules)?;
+        let leading_zeros = || {
+            self.semantics
+                .semantic_type(self.n_values, options.type_tolerance)
+                == Some(SemanticType::ZeroPaddedCode)
+        };
+        let applies = match rule.condition {
+            HintCondition::Fits => self.fits(rule.hint, options),
+            HintCondition::LeadingZeros => leading_zeros(),
+            HintCondition::LeadingZerosOrMixedLengths => {
+                self.n_values == 0
+                    || leading_zeros()
+                    || self
+                        .min_bytes
+                        .is_some_and(|min| min < self.text_length.max_bytes)
+            }
+        };
+        Some(HeaderHint {
+            rule: rule.clone(),
+            value_type: ty,
+            applied: applies && rule.hint != ty,
+        })
+    }
+
+    /// Unit of the Unix timestamps in this field, if its values all are plausible timestamps.
+    /// Ten-digit numbers in seconds are common enough (e.g. phone numbers) that they are only
+    /// taken for timestamps when the header `name` suggests so.
+    fn epoch_unit(&self, ty: Type, name: Option<&str>) -> Option<EpochUnit> {
+        if !matches!(ty, Type::Unsigned | Type::Float) {
+            return None;
+        }
+        let (min, max) = self.numeric_range?;
+        let unit = EpochUnit::of(min)?;
+        if EpochUnit::of(max) != Some(unit) {
+            return None;
+        }
+        (unit != EpochUnit::Seconds || name.is_some_and(is_timestamp_header)).then_some(unit)
+    }
+
+    /// Narrowest width that fits the integers of this field, if it is `Unsigned`, `Signed` or
+    /// `BigInteger` (and none overflows 128 bits).
+    fn integer_width(&self, ty: Type) -> Option<IntegerWidth> {
+        let (min, max) = self.integer_range?;
+        match ty {
+            Type::BigInteger if self.integer_overflow => None,
+            Type::Unsigned => Some(IntegerWidth::unsigned(max)),
+            Type::BigInteger if min >= 0 => Some(IntegerWidth::unsigned(max)),
+            Type::Signed | Type::BigInteger => IntegerWidth::signed(min, max),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn field_metadata(
+        &self,
+        ty: Type,
+        name: Option<&str>,
+        options: &InferenceOptions,
+    ) -> FieldMetadata {
+        let type_counts = TypeGuesses::FLAGS
+            .iter()
+            .enumerate()
+            .filter_map(|(i, flag)| guess_type(*flag.value()).map(|ty| (ty, self.counts[i])))
+            .collect();
+        let (n_outliers, mut outlier_examples) = match type_guess(ty) {
+            Some(guess) => {
+                // safety: every flag returned by `type_guess` is a flag of `TypeGuesses`
+                let i = TypeGuesses::FLAGS
+                    .iter()
+                    .position(|flag| *flag.value() == guess)
+                    .unwrap();
+                (self.n_values - self.counts[i], self.outliers[i].clone())
+            }
+            None => (0, vec![]),
+        };
+        let pii = match ty {
+            Type::Date | Type::DateTime if name.is_some_and(is_birthdate_header) => {
+                Some(PiiMatch {
+                    category: PiiCategory::Birthdate,
+                    match_rate: (self.n_values - n_outliers) as f64 / self.n_values as f64,
+                })
+            }
+            _ => self.pii.pii(self.n_values),
+        };
+        if options.redact_pii && pii.is_some() {
+            outlier_examples = outlier_examples
+                .iter()
+                .map(|example| redact(example))
+                .collect();
+        }
+        let (date_format, date_order, date_locale) = match ty {
+            Type::Date | Type::DateTime => (
+                self.date_formats.format().map(str::to_string),
+                self.date_formats.order(),
+                self.date_locales
+                    .as_ref()
+                    .and_then(|locales| locales.first().copied()),
+            ),
+            _ => (None, None, None),
+        };
+        FieldMetadata {
+            n_values: self.n_values,
+            n_empty: self.n_empty,
+            type_counts,
+            n_outliers,
+            outlier_examples,
+            date_format,
+            date_order,
+            date_locale,
+            special_numbers: self.special_numbers.clone(),
+            semantic_type: self
+                .semantics
+                .semantic_type(self.n_values, options.type_tolerance),
+            header_hint: None,
+            type_annotation: None,
+            unit: name.and_then(|name| header_unit(name, ty)),
+            custom_type: self.detectors.custom_type(ty, self.n_values, options),
+            custom_type_counts: self.detectors.counts(&options.type_detectors),
+            pii,
+            json: match ty {
+                Type::Text => self.json.summary(),
+                _ => None,
+            },
+            list: match ty {
+                Type::Text => self.lists.summary(self.n_values, options.type_tolerance),
+                _ => None,
+            },
+            geometry: match ty {
+                Type::Text => self.geometries.geometry(),
+                _ => None,
+            },
+            coordinate: match (ty, name, self.numeric_range) {
+                (Type::Unsigned | Type::Signed | Type::Float, Some(name), Some(range)) => {
+                    coordinate_axis(name, range).map(|axis| Coordinate { axis, pair: None })
+                }
+                _ => None,
+            },
+            integer_width: self.integer_width(ty),
+            numeric_size: match ty {
+                Type::Unsigned | Type::Signed | Type::BigInteger | Type::Float => self.numeric_size,
+                _ => None,
+            },
+            text_length: match ty {
+                Type::Text => Some(self.text_length),
+                _ => None,
+            },
+            epoch_unit: self.epoch_unit(ty, name),
+            datetime_zone: match ty {
+                Type::DateTime => self.zones.zone(),
+                _ => None,
+            },
+        }
+    }
+}
+
+// The value of the integer `s` (up to 128 bits), as a lower bound and an upper bound (with
+// negative values counting as zero towards the upper bound).
+fn parse_integer(s: &str) -> Option<(i128, u128)> {
+    match s.parse::<i128>() {
+        Ok(value) => Some((value, u128::try_from(value).unwrap_or(0))),
+        Err(_) => s.parse::<u128>().ok().map(|value| (i128::MAX, value)),
+    }
+}
+
+// The `Type` represented by a single `TypeGuesses` flag (`None` for the `NULL` flag, which is
+// never matched by a non-empty value).
+const fn guess_type(guess: TypeGuesses) -> Option<Type> {
+    match guess {
+        TypeGuesses::BOOLEAN => Some(Type::Boolean),
+        TypeGuesses::UNSIGNED => Some(Type::Unsigned),
+        TypeGuesses::SIGNED => Some(Type::Signed),
+        TypeGuesses::BIGINT => Some(Type::BigInteger),
+        TypeGuesses::FLOAT => Some(Type::Float),
+        TypeGuesses::DATE => Some(Type::Date),
+        TypeGuesses::DATETIME => Some(Type::DateTime),
+        TypeGuesses::TEXT => Some(Type::Text),
+        _ => None,
+    }
+}
+
+// The `TypeGuesses` flag a value has to match to fit `Type` (`None` if any value fits).
+const fn type_guess(ty: Type) -> Option<TypeGuesses> {
+    match ty {
+        Type::Boolean => Some(TypeGuesses::BOOLEAN),
+        Type::Unsigned => Some(TypeGuesses::UNSIGNED),
+        Type::Signed => Some(TypeGuesses::SIGNED),
+        Type::BigInteger => Some(TypeGuesses::BIGINT),
+        Type::Float => Some(TypeGuesses::FLOAT),
+        Type::Date => Some(TypeGuesses::DATE),
+        Type::DateTime => Some(TypeGuesses::DATETIME),
+        Type::Text | Type::NULL => None,
+    }
 }
 
 /// The valid field types for fields in a CSV record.
@@ -135,6 +1253,8 @@ pub enum Type {
     Unsigned,
     /// Signed integer
     Signed,
+    /// Integer too large (or too small) to fit in 64 bits
+    BigInteger,
     /// Text (any field can be a type)
     Text,
     /// Boolean (true / false or 0 / 1)
@@ -148,6 +1268,24 @@ pub enum Type {
     /// Null
     NULL,
 }
+impl Type {
+    /// Position of this type in the order in which `TypeGuesses::best` picks the narrowest type
+    /// of a field.
+    pub(crate) const fn rank(self) -> usize {
+        match self {
+            Type::NULL => 0,
+            Type::Boolean => 1,
+            Type::Unsigned => 2,
+            Type::Signed => 3,
+            Type::BigInteger => 4,
+            Type::Float => 5,
+            Type::DateTime => 6,
+            Type::Date => 7,
+            Type::Text => 8,
+        }
+    }
+}
+
 pub(crate) fn get_best_types(guesses: &[TypeGuesses]) -> Vec<Type> {
     guesses.iter().map(|guess| guess.best()).collect()
 }
@@ -159,6 +1297,7 @@ impl fmt::Display for Type {
             match *self {
                 Type::Unsigned => "Unsigned",
                 Type::Signed => "Signed",
+                Type::BigInteger => "BigInteger",
                 Type::Text => "Text",
                 Type::Boolean => "Boolean",
                 Type::Float => "Float",
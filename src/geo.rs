@@ -0,0 +1,248 @@
+use std::fmt;
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde_json::Value;
+
+use crate::{field_type::Type, header::header_words, metadata::FieldMetadata};
+
+/// Whether a numeric field holds latitudes or longitudes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoordinateAxis {
+    /// Latitude, between -90 and 90 degrees
+    Latitude,
+    /// Longitude, between -180 and 180 degrees
+    Longitude,
+}
+
+/// A field holding latitudes or longitudes, and the field it pairs up with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Coordinate {
+    /// Whether the field holds latitudes or longitudes.
+    pub axis: CoordinateAxis,
+    /// The index of the field holding the other coordinate of the same points, if any.
+    pub pair: Option<usize>,
+}
+
+/// The encoding of the geometries of a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeometryFormat {
+    /// Well-known text (e.g. `POINT(30 10)`)
+    Wkt,
+    /// GeoJSON geometry or feature (e.g. `{"type": "Point", "coordinates": [30, 10]}`)
+    GeoJson,
+}
+
+/// The type of the geometries of a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeometryType {
+    /// Point
+    Point,
+    /// LineString
+    LineString,
+    /// Polygon
+    Polygon,
+    /// MultiPoint
+    MultiPoint,
+    /// MultiLineString
+    MultiLineString,
+    /// MultiPolygon
+    MultiPolygon,
+    /// GeometryCollection
+    GeometryCollection,
+    /// A mix of the above
+    Mixed,
+}
+
+impl GeometryType {
+    const NAMED: [(GeometryType, &'static str); 7] = [
+        (GeometryType::Point, "Point"),
+        (GeometryType::LineString, "LineString"),
+        (GeometryType::Polygon, "Polygon"),
+        (GeometryType::MultiPoint, "MultiPoint"),
+        (GeometryType::MultiLineString, "MultiLineString"),
+        (GeometryType::MultiPolygon, "MultiPolygon"),
+        (GeometryType::GeometryCollection, "GeometryCollection"),
+    ];
+
+    // The geometry type named `name`, without regard to case.
+    fn named(name: &str) -> Option<GeometryType> {
+        GeometryType::NAMED
+            .iter()
+            .find(|(_, type_name)| type_name.eq_ignore_ascii_case(name))
+            .map(|&(ty, _)| ty)
+    }
+}
+
+/// Summary of a `Text` field whose values all are geometries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Geometry {
+    /// The encoding of the geometries.
+    pub format: GeometryFormat,
+    /// The type of the geometries.
+    pub geometry_type: GeometryType,
+}
+
+static WKT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?i)\s*(SRID=\d+;\s*)?([a-z]+)(\s*(Z|M|ZM))?\s*(\(.*\)|EMPTY)\s*$").unwrap()
+});
+
+// The type of the WKT or GeoJSON geometry `s`, if it is one.
+fn geometry(s: &str) -> Option<(GeometryFormat, GeometryType)> {
+    if s.trim_start().starts_with('{') {
+        let value = serde_json::from_str::<Value>(s).ok()?;
+        let geometry = match value.get("type")?.as_str()? {
+            "Feature" => value.get("geometry")?,
+            _ => &value,
+        };
+        let ty = GeometryType::named(geometry.get("type")?.as_str()?)?;
+        Some((GeometryFormat::GeoJson, ty))
+    } else {
+        let groups = WKT.captures(s)?;
+        Some((GeometryFormat::Wkt, GeometryType::named(&groups[2])?))
+    }
+}
+
+/// Running tally of the geometries in a single column. Values stop being parsed as soon as one of
+/// them is not a geometry (or is not in the same format as the others).
+#[derive(Debug, Default, Clone)]
+pub(crate) struct GeometryTally {
+    geometry: Option<Geometry>,
+    not_geometry: bool,
+}
+
+impl GeometryTally {
+    pub(crate) fn add(&mut self, s: &str) {
+        if self.not_geometry {
+            return;
+        }
+        let Some((format, geometry_type)) = geometry(s) else {
+            self.not_geometry = true;
+            return;
+        };
+        let seen = self.geometry.get_or_insert(Geometry {
+            format,
+            geometry_type,
+        });
+        if seen.format != format {
+            self.not_geometry = true;
+        } else if seen.geometry_type != geometry_type {
+            seen.geometry_type = GeometryType::Mixed;
+        }
+    }
+
+    /// Summary of the geometries of this column, if every (non-empty) value seen is a geometry.
+    pub(crate) fn geometry(&self) -> Option<Geometry> {
+        self.geometry.filter(|_| !self.not_geometry)
+    }
+}
+
+const LATITUDE_WORDS: [&str; 2] = ["lat", "latitude"];
+const LONGITUDE_WORDS: [&str; 4] = ["lon", "lng", "long", "longitude"];
+
+/// The axis of a numeric field named `name`, whose values range from `min` to `max`: the header
+/// has to name the axis, and the values have to fit its range.
+pub(crate) fn coordinate_axis(name: &str, (min, max): (f64, f64)) -> Option<CoordinateAxis> {
+    let words = header_words(name);
+    let has_word =
+        |axis_words: &[&str]| words.iter().any(|word| axis_words.contains(&word.as_str()));
+    if has_word(&LATITUDE_WORDS) && min >= -90.0 && max <= 90.0 {
+        Some(CoordinateAxis::Latitude)
+    } else if has_word(&LONGITUDE_WORDS) && min >= -180.0 && max <= 180.0 {
+        Some(CoordinateAxis::Longitude)
+    } else {
+        None
+    }
+}
+
+// The words of the header `name` other than its latitude or longitude word (e.g. `pickup` for
+// `pickup_lat`), used to pair up coordinates.
+fn coordinate_prefix(name: &str) -> Vec<String> {
+    header_words(name)
+        .into_iter()
+        .filter(|word| {
+            !LATITUDE_WORDS.contains(&word.as_str()) && !LONGITUDE_WORDS.contains(&word.as_str())
+        })
+        .collect()
+}
+
+/// Coordinates of a file without a header row: a `Float` field within latitude range followed by
+/// a `Float` field within longitude range is taken for a latitude and longitude pair.
+pub(crate) fn unnamed_coordinates(
+    types: &[Type],
+    ranges: &[Option<(f64, f64)>],
+) -> Vec<Option<Coordinate>> {
+    let mut coordinates = vec![None; types.len()];
+    let mut i = 0;
+    while i + 1 < types.len() {
+        let axes = (
+            types[i] == Type::Float
+                && ranges[i].is_some_and(|(min, max)| min >= -90.0 && max <= 90.0),
+            types[i + 1] == Type::Float
+                && ranges[i + 1].is_some_and(|(min, max)| min >= -180.0 && max <= 180.0),
+        );
+        if axes == (true, true) {
+            coordinates[i] = Some(Coordinate {
+                axis: CoordinateAxis::Latitude,
+                pair: Some(i + 1),
+            });
+            coordinates[i + 1] = Some(Coordinate {
+                axis: CoordinateAxis::Longitude,
+                pair: Some(i),
+            });
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+    coordinates
+}
+
+/// Pair up each latitude field with a longitude field: the one whose header has the same other
+/// words (e.g. `pickup_lat` and `pickup_lon`), or else the nearest unpaired one.
+pub(crate) fn pair_coordinates(fields: &[String], field_metadata: &mut [FieldMetadata]) {
+    let axis_fields = |axis: CoordinateAxis| -> Vec<usize> {
+        field_metadata
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| field.coordinate.is_some_and(|c| c.axis == axis))
+            .map(|(i, _)| i)
+            .collect()
+    };
+    let latitudes = axis_fields(CoordinateAxis::Latitude);
+    let mut longitudes = axis_fields(CoordinateAxis::Longitude);
+    let mut pairs = vec![];
+    // pair up fields with matching header words first, then the nearest fields
+    for by_prefix in [true, false] {
+        for &lat in &latitudes {
+            if pairs.iter().any(|&(paired, _)| paired == lat) {
+                continue;
+            }
+            let candidates = longitudes.iter().enumerate().filter(|&(_, &lon)| {
+                !by_prefix
+                    || fields.get(lat).map(|name| coordinate_prefix(name))
+                        == fields.get(lon).map(|name| coordinate_prefix(name))
+            });
+            if let Some((j, &lon)) = candidates.min_by_key(|&(_, &lon)| lat.abs_diff(lon)) {
+                pairs.push((lat, lon));
+                longitudes.remove(j);
+            }
+        }
+    }
+    for (lat, lon) in pairs {
+        for (field, pair) in [(lat, lon), (lon, lat)] {
+            if let Some(coordinate) = field_metadata[field].coordinate.as_mut() {
+                coordinate.pair = Some(pair);
+            }
+        }
+    }
+}
+
+impl fmt::Display for GeometryType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match GeometryType::NAMED.iter().find(|(ty, _)| ty == self) {
+            Some((_, name)) => write!(f, "{name}"),
+            None => write!(f, "Mixed"),
+        }
+    }
+}
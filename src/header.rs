@@ -0,0 +1,446 @@
+use std::sync::LazyLock;
+
+use deunicode::deunicode;
+use regex::Regex;
+
+use crate::{
+    field_type::Type, geo::CoordinateAxis, metadata::FieldMetadata, pii::PiiCategory,
+    semantic::SemanticType,
+};
+
+/// Split a header name into lowercase words, breaking on non-alphanumeric characters and on
+/// camelCase boundaries (e.g. `createdAt` and `created_at` are both `["created", "at"]`).
+pub(crate) fn header_words(name: &str) -> Vec<String> {
+    let mut words = vec![];
+    let mut word = String::new();
+    let mut prev_lower = false;
+    for c in name.chars() {
+        if !c.is_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            prev_lower = false;
+            continue;
+        }
+        if c.is_uppercase() && prev_lower && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        prev_lower = c.is_lowercase() || c.is_numeric();
+        word.extend(c.to_lowercase());
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+/// Whether a header name suggests that its field holds timestamps (e.g. `ts`, `created_at`,
+/// `eventTime`).
+pub(crate) fn is_timestamp_header(name: &str) -> bool {
+    const TIMESTAMP_WORDS: [&str; 10] = [
+        "ts",
+        "timestamp",
+        "time",
+        "epoch",
+        "datetime",
+        "date",
+        "created",
+        "updated",
+        "modified",
+        "deleted",
+    ];
+    let words = header_words(name);
+    words
+        .iter()
+        .any(|word| TIMESTAMP_WORDS.contains(&word.as_str()))
+        || (words.len() > 1 && words.last().is_some_and(|word| word == "at"))
+}
+
+/// Whether a header name suggests that its field holds birthdates (e.g. `dob`, `birth_date`,
+/// `dateOfBirth`).
+pub(crate) fn is_birthdate_header(name: &str) -> bool {
+    const BIRTHDATE_WORDS: [&str; 5] = ["dob", "birth", "birthday", "birthdate", "born"];
+    header_words(name)
+        .iter()
+        .any(|word| BIRTHDATE_WORDS.contains(&word.as_str()))
+}
+
+/// When a [`HeaderRule`](struct.HeaderRule.html) applies to a field with a matching header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HintCondition {
+    /// When at least the type tolerance of the values of the field fit the hinted type (which
+    /// includes fields without any value).
+    Fits,
+    /// When the values of the field are numeric codes with leading zeros (see
+    /// [`SemanticType::ZeroPaddedCode`](enum.SemanticType.html#variant.ZeroPaddedCode)).
+    LeadingZeros,
+    /// When the values of the field are numeric codes with leading zeros, or values of different
+    /// lengths (e.g. zip codes that lost their leading zeros to a spreadsheet), or when the field
+    /// has no values at all.
+    LeadingZerosOrMixedLengths,
+}
+
+/// A rule of thumb that makes fields whose header matches a pattern lean toward a type (e.g.
+/// `is_*` fields toward `Boolean`), used when calling `header_rules` on `Sniffer`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeaderRule {
+    /// Pattern matched against the header, where `*` matches any run of characters (e.g. `*_id`
+    /// or `is_*`). Headers are matched as their lowercase words joined by `_`, so that
+    /// `createdAt`, `Created At` and `created_at` all match `*_at`.
+    pub pattern: String,
+    /// The type that fields with a matching header lean toward.
+    pub hint: Type,
+    /// When the rule applies.
+    pub condition: HintCondition,
+}
+
+impl HeaderRule {
+    /// Create a new header rule.
+    pub fn new(pattern: &str, hint: Type, condition: HintCondition) -> HeaderRule {
+        HeaderRule {
+            pattern: pattern.to_string(),
+            hint,
+            condition,
+        }
+    }
+
+    /// The default header rules: zip and postal codes that are empty, or with leading zeros or of
+    /// different lengths, lean toward `Text`, `is_*` and `has_*` fields toward `Boolean`, `*_id` fields with leading
+    /// zeros toward `Text`, `*_date` fields toward `Date`, `*_at` fields toward `DateTime`, and
+    /// `*_amount` and `*_price` fields toward `Float`.
+    pub fn defaults() -> Vec<HeaderRule> {
+        use HintCondition::{Fits, LeadingZeros, LeadingZerosOrMixedLengths};
+        [
+            ("zip", Type::Text, LeadingZerosOrMixedLengths),
+            ("*_zip", Type::Text, LeadingZerosOrMixedLengths),
+            ("zip_*", Type::Text, LeadingZerosOrMixedLengths),
+            ("*_zip_*", Type::Text, LeadingZerosOrMixedLengths),
+            ("zipcode", Type::Text, LeadingZerosOrMixedLengths),
+            ("*_zipcode", Type::Text, LeadingZerosOrMixedLengths),
+            ("*postal_code", Type::Text, LeadingZerosOrMixedLengths),
+            ("is_*", Type::Boolean, Fits),
+            ("has_*", Type::Boolean, Fits),
+            ("id", Type::Text, LeadingZeros),
+            ("*_id", Type::Text, LeadingZeros),
+            ("date", Type::Date, Fits),
+            ("*_date", Type::Date, Fits),
+            ("*_at", Type::DateTime, Fits),
+            ("amount", Type::Float, Fits),
+            ("*_amount", Type::Float, Fits),
+            ("price", Type::Float, Fits),
+            ("*_price", Type::Float, Fits),
+        ]
+        .into_iter()
+        .map(|(pattern, hint, condition)| HeaderRule::new(pattern, hint, condition))
+        .collect()
+    }
+
+    /// Whether the header `name` matches the pattern of this rule.
+    fn matches(&self, name: &str) -> bool {
+        glob_match(&self.pattern.to_lowercase(), &header_words(name).join("_"))
+    }
+}
+
+/// The influence of a header rule on the type of a field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeaderHint {
+    /// The first header rule whose pattern matches the header of the field.
+    pub rule: HeaderRule,
+    /// The type inferred from the values of the field alone.
+    pub value_type: Type,
+    /// Whether the rule applied, making the field of type `rule.hint` instead of `value_type`.
+    pub applied: bool,
+}
+
+/// The first of `rules` whose pattern matches the header `name`.
+pub(crate) fn matching_rule<'a>(name: &str, rules: &'a [HeaderRule]) -> Option<&'a HeaderRule> {
+    rules.iter().find(|rule| rule.matches(name))
+}
+
+// Whether `text` matches `pattern`, where `*` matches any run of characters.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    // safety: `split` always yields at least one part
+    let first = parts.next().unwrap();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // no `*` in the pattern
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(i) => rest = &rest[i + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+/// How to name the fields of a file without a header row, used when calling `field_naming` on
+/// `Sniffer`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldNaming {
+    /// Pattern of the generated names, where `{n}` is replaced by the position of the field
+    /// counting from 1 and `{i}` by its index counting from 0 (e.g. `field_{n}` or `col{i}`).
+    pub pattern: String,
+    /// Whether to name fields after what their values represent (e.g. `email`, `date` or
+    /// `latitude`) when that is known, falling back to the pattern otherwise. Names used by more
+    /// than one field get a `_2`, `_3`, ... suffix after the first.
+    pub semantic: bool,
+}
+
+impl FieldNaming {
+    /// Create a new field naming.
+    pub fn new(pattern: &str, semantic: bool) -> FieldNaming {
+        FieldNaming {
+            pattern: pattern.to_string(),
+            semantic,
+        }
+    }
+
+    /// The name of each field, given the name suggested by its values (if any).
+    pub(crate) fn names(&self, suggestions: &[Option<String>]) -> Vec<String> {
+        let mut names: Vec<String> = Vec::with_capacity(suggestions.len());
+        for (i, suggestion) in suggestions.iter().enumerate() {
+            let name = match suggestion {
+                Some(base) if self.semantic => {
+                    let mut name = base.clone();
+                    let mut n = 1;
+                    while names.contains(&name) {
+                        n += 1;
+                        name = format!("{base}_{n}");
+                    }
+                    name
+                }
+                _ => self
+                    .pattern
+                    .replace("{n}", &(i + 1).to_string())
+                    .replace("{i}", &i.to_string()),
+            };
+            names.push(name);
+        }
+        names
+    }
+}
+
+impl Default for FieldNaming {
+    /// Fields are named `field_1`, `field_2`, ... without regard to their values.
+    fn default() -> FieldNaming {
+        FieldNaming::new("field_{n}", false)
+    }
+}
+
+/// The name suggested by the values of a field of type `ty`, from what they represent.
+pub(crate) fn suggested_name(ty: Type, metadata: &FieldMetadata) -> Option<String> {
+    let name = if let Some(custom_type) = &metadata.custom_type {
+        return Some(custom_type.clone());
+    } else if let Some(coordinate) = metadata.coordinate {
+        match coordinate.axis {
+            CoordinateAxis::Latitude => "latitude",
+            CoordinateAxis::Longitude => "longitude",
+        }
+    } else if let Some(semantic_type) = metadata.semantic_type {
+        match semantic_type {
+            SemanticType::Uuid => "uuid",
+            SemanticType::Url => "url",
+            SemanticType::Email => "email",
+            SemanticType::Ipv4 => "ipv4",
+            SemanticType::Ipv6 => "ipv6",
+            SemanticType::ZeroPaddedCode => "code",
+        }
+    } else if let Some(pii) = metadata.pii {
+        match pii.category {
+            PiiCategory::Email => "email",
+            PiiCategory::Phone => "phone",
+            PiiCategory::NationalId => "national_id",
+            PiiCategory::CreditCard => "card_number",
+            PiiCategory::Birthdate => "birthdate",
+        }
+    } else if metadata.geometry.is_some() {
+        "geometry"
+    } else if metadata.json.is_some() {
+        "json"
+    } else if metadata.epoch_unit.is_some() {
+        "timestamp"
+    } else {
+        match ty {
+            Type::Date => "date",
+            Type::DateTime => "datetime",
+            _ => return None,
+        }
+    };
+    Some(name.to_string())
+}
+
+/// A problem with the names in the header row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HeaderWarning {
+    /// Several fields share the same name once normalized (e.g. `First Name` and `first_name`,
+    /// or `id` and `id` followed by a zero-width space).
+    Duplicate {
+        /// The shared name, as written in the first of the fields (without the whitespace and
+        /// invisible characters around it).
+        name: String,
+        /// The indices of the fields sharing it.
+        fields: Vec<usize>,
+    },
+    /// A field has an empty name (or one made only of whitespace and invisible characters).
+    Empty {
+        /// The index of the field.
+        field: usize,
+    },
+}
+
+// Characters that take no room on screen (zero-width spaces and joiners, word joiner, byte order
+// mark and soft hyphen), which make names look the same while comparing different.
+const INVISIBLE_CHARS: [char; 6] = [
+    '\u{200B}', '\u{200C}', '\u{200D}', '\u{2060}', '\u{FEFF}', '\u{00AD}',
+];
+
+// The header `name` without invisible characters, with its whitespace (including non-breaking
+// spaces) turned into plain spaces and trimmed.
+fn clean_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !INVISIBLE_CHARS.contains(c))
+        .map(|c| if c.is_whitespace() { ' ' } else { c })
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+/// Whether the header `name` is that of a row-index column, as written by pandas (`Unnamed: 0`)
+/// or R (an empty header).
+pub(crate) fn is_index_header(name: &str) -> bool {
+    let name = clean_name(name);
+    name.is_empty() || name.eq_ignore_ascii_case("unnamed: 0")
+}
+
+/// The duplicate and empty names among the header `fields`.
+pub(crate) fn header_warnings(fields: &[String]) -> Vec<HeaderWarning> {
+    let names: Vec<String> = fields.iter().map(|field| clean_name(field)).collect();
+    // compared as in `normalize_fields`, before the names are made unique
+    let keys: Vec<String> = names
+        .iter()
+        .map(|name| header_words(&deunicode(name)).join("_"))
+        .collect();
+    let mut warnings = vec![];
+    for (i, (name, key)) in names.iter().zip(&keys).enumerate() {
+        if name.is_empty() {
+            warnings.push(HeaderWarning::Empty { field: i });
+        } else if !key.is_empty() && !keys[..i].contains(key) {
+            let duplicates: Vec<usize> = (i..keys.len()).filter(|&j| keys[j] == *key).collect();
+            if duplicates.len() > 1 {
+                warnings.push(HeaderWarning::Duplicate {
+                    name: name.clone(),
+                    fields: duplicates,
+                });
+            }
+        }
+    }
+    warnings
+}
+
+/// The header `fields` made safe to use as identifiers (e.g. SQL column names): transliterated
+/// to ASCII, in snake_case, prefixed with `_` if they would start with a digit, and made unique
+/// with a `_2`, `_3`, ... suffix. Empty names are replaced with the names `naming` generates.
+pub(crate) fn normalize_fields(fields: &[String], naming: &FieldNaming) -> Vec<String> {
+    let generated = naming.names(&vec![None; fields.len()]);
+    let mut names: Vec<String> = Vec::with_capacity(fields.len());
+    for (field, generated) in fields.iter().zip(generated) {
+        let mut base = header_words(&deunicode(&clean_name(field))).join("_");
+        if base.is_empty() {
+            base = header_words(&generated).join("_");
+        }
+        if base.starts_with(|c: char| c.is_ascii_digit()) {
+            base.insert(0, '_');
+        }
+        let mut name = base.clone();
+        let mut n = 1;
+        while names.contains(&name) {
+            n += 1;
+            name = format!("{base}_{n}");
+        }
+        names.push(name);
+    }
+    names
+}
+
+/// A type written in a header (e.g. `int` in `age:int`).
+#[derive(Debug, Clone)]
+pub(crate) struct TypeAnnotation {
+    /// The header without its type annotation (e.g. `age` for `age:int`).
+    pub(crate) name: String,
+    /// The type annotation as written (e.g. `int`).
+    pub(crate) annotation: String,
+    /// The type the annotation stands for.
+    pub(crate) ty: Type,
+}
+
+// `name:type`, as in Neo4j import files (e.g. `age:int`, `tags:string[]` or `id:ID(Person)`).
+static COLON_ANNOTATION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?P<name>[^:]*):(?P<type>[A-Za-z_]+(\[\])?)(\([^)]*\))?$").unwrap()
+});
+
+// `name (type)` or `name [type]` (e.g. `price (float)`).
+static BRACKET_ANNOTATION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?P<name>.*?)\s*(\((?P<paren>[A-Za-z_]+)\)|\[(?P<bracket>[A-Za-z_]+)\])$")
+        .unwrap()
+});
+
+// The type named by the annotation `annotation`, if it is a known one. The Neo4j-only names
+// (e.g. `ID` or `type`) are only known in `name:type` annotations, as they are common words
+// between brackets.
+fn annotated_type(annotation: &str, neo4j: bool) -> Option<Type> {
+    if annotation.ends_with("[]") {
+        // arrays (e.g. Neo4j's `string[]`) are lists of values
+        return Some(Type::Text);
+    }
+    let ty = match annotation.to_lowercase().as_str() {
+        "string" | "str" | "text" | "varchar" | "char" => Type::Text,
+        // Neo4j's node ids, relationship ends, labels and relationship types
+        "id" | "start_id" | "end_id" | "label" | "type" if neo4j => Type::Text,
+        "int" | "integer" | "long" | "short" | "byte" | "bigint" | "smallint" => Type::Signed,
+        "uint" | "unsigned" => Type::Unsigned,
+        "float" | "double" | "decimal" | "numeric" | "number" | "real" => Type::Float,
+        "bool" | "boolean" => Type::Boolean,
+        "date" => Type::Date,
+        "datetime" | "timestamp" | "localdatetime" => Type::DateTime,
+        _ => return None,
+    };
+    Some(ty)
+}
+
+/// The type annotation of the header `name` (e.g. `age:int`, `id:ID(Person)` or
+/// `price (float)`), if it has one naming a known type. Headers made only of an annotation (e.g.
+/// Neo4j's `:ID` or `:START_ID`) are named after it.
+pub(crate) fn type_annotation(name: &str) -> Option<TypeAnnotation> {
+    let (field, annotation, neo4j) = if let Some(caps) = COLON_ANNOTATION.captures(name) {
+        (
+            caps.name("name")?.as_str().trim(),
+            caps.name("type")?.as_str(),
+            true,
+        )
+    } else {
+        let caps = BRACKET_ANNOTATION.captures(name)?;
+        let annotation = caps.name("paren").or_else(|| caps.name("bracket"))?;
+        (
+            caps.name("name")?.as_str().trim(),
+            annotation.as_str(),
+            false,
+        )
+    };
+    let ty = annotated_type(annotation, neo4j)?;
+    let name = if field.is_empty() {
+        annotation.trim_end_matches("[]").to_lowercase()
+    } else {
+        field.to_string()
+    };
+    Some(TypeAnnotation {
+        name,
+        annotation: annotation.to_string(),
+        ty,
+    })
+}
@@ -0,0 +1,89 @@
+use std::fmt;
+
+use hashbrown::HashSet;
+use serde_json::Value;
+
+/// The kind of JSON values held by a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonKind {
+    /// JSON objects (e.g. `{"id": 1, "tags": []}`)
+    Object,
+    /// JSON arrays (e.g. `[1, 2, 3]`)
+    Array,
+    /// JSON strings, numbers, booleans or nulls (e.g. `"text"`)
+    Scalar,
+    /// A mix of the above
+    Mixed,
+}
+
+/// Summary of a `Text` field whose values all are JSON documents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonSummary {
+    /// The kind of JSON values of the field.
+    pub kind: JsonKind,
+    /// The union of the top-level keys of the JSON objects of the field, in alphabetical order.
+    pub keys: Vec<String>,
+}
+
+/// Running tally of the JSON values in a single column. Values stop being parsed as soon as one
+/// of them is not JSON.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct JsonTally {
+    kind: Option<JsonKind>,
+    keys: HashSet<String>,
+    not_json: bool,
+}
+
+impl JsonTally {
+    pub(crate) fn add(&mut self, s: &str) {
+        if self.not_json {
+            return;
+        }
+        let Ok(value) = serde_json::from_str::<Value>(s) else {
+            self.not_json = true;
+            return;
+        };
+        let kind = match value {
+            Value::Object(object) => {
+                for key in object.keys() {
+                    if !self.keys.contains(key) {
+                        self.keys.insert(key.clone());
+                    }
+                }
+                JsonKind::Object
+            }
+            Value::Array(_) => JsonKind::Array,
+            _ => JsonKind::Scalar,
+        };
+        if *self.kind.get_or_insert(kind) != kind {
+            self.kind = Some(JsonKind::Mixed);
+        }
+    }
+
+    /// Summary of the JSON values of this column, if every (non-empty) value seen is JSON.
+    pub(crate) fn summary(&self) -> Option<JsonSummary> {
+        if self.not_json {
+            return None;
+        }
+        self.kind.map(|kind| {
+            let mut keys: Vec<String> = self.keys.iter().cloned().collect();
+            keys.sort_unstable();
+            JsonSummary { kind, keys }
+        })
+    }
+}
+
+impl fmt::Display for JsonKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match *self {
+                JsonKind::Object => "Object",
+                JsonKind::Array => "Array",
+                JsonKind::Scalar => "Scalar",
+                JsonKind::Mixed => "Mixed",
+            }
+        )
+    }
+}
@@ -0,0 +1,289 @@
+use std::cmp::Ordering;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use csv::ByteRecord;
+use hashbrown::HashSet;
+
+use crate::{field_type::Type, metadata::FieldMetadata};
+
+// Number of leading fields whose pairs are tallied as candidate keys.
+const MAX_KEY_FIELDS: usize = 16;
+
+/// The direction in which a field is sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    /// From the smallest value to the largest
+    Ascending,
+    /// From the largest value to the smallest
+    Descending,
+}
+
+/// A field by which the sampled records are sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortOrder {
+    /// The index of the field.
+    pub field: usize,
+    /// The direction in which the field is sorted (ties allowed).
+    pub direction: SortDirection,
+}
+
+// Hashes of the distinct values of a field or pair of fields, until a value repeats or is empty.
+#[derive(Debug, Default, Clone)]
+struct Distinct {
+    hashes: HashSet<u64>,
+    repeated: bool,
+}
+
+impl Distinct {
+    fn add(&mut self, values: &[&[u8]]) {
+        if self.repeated {
+            return;
+        }
+        if values.iter().any(|value| value.is_empty()) {
+            self.repeated = true;
+            self.hashes = HashSet::new();
+            return;
+        }
+        let mut hasher = DefaultHasher::new();
+        values.hash(&mut hasher);
+        if !self.hashes.insert(hasher.finish()) {
+            self.repeated = true;
+            self.hashes = HashSet::new();
+        }
+    }
+}
+
+// Whether a sequence of values only goes up, or only goes down (ties allowed), and whether it
+// changes at all.
+#[derive(Debug, Clone, Copy)]
+struct Monotonic {
+    ascending: bool,
+    descending: bool,
+    changed: bool,
+}
+
+impl Default for Monotonic {
+    fn default() -> Monotonic {
+        Monotonic {
+            ascending: true,
+            descending: true,
+            changed: false,
+        }
+    }
+}
+
+impl Monotonic {
+    // Tally a step from a value to the next, given how they compare (`None` if they cannot be
+    // compared, which breaks the order).
+    fn add(&mut self, step: Option<Ordering>) {
+        self.ascending &= matches!(step, Some(Ordering::Less | Ordering::Equal));
+        self.descending &= matches!(step, Some(Ordering::Greater | Ordering::Equal));
+        self.changed |= step != Some(Ordering::Equal);
+    }
+
+    fn direction(&self) -> Option<SortDirection> {
+        match (self.ascending, self.descending, self.changed) {
+            (true, false, true) => Some(SortDirection::Ascending),
+            (false, true, true) => Some(SortDirection::Descending),
+            _ => None,
+        }
+    }
+}
+
+// The sign and the digits (without leading zeros) of the integer `value` of any size.
+fn integer_parts(value: &[u8]) -> Option<(bool, &[u8])> {
+    let (negative, digits) = match value.split_first()? {
+        (b'-', digits) => (true, digits),
+        (b'+', digits) => (false, digits),
+        _ => (false, value),
+    };
+    if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    let digits = &digits[digits.iter().take_while(|&&d| d == b'0').count()..];
+    Some((negative && !digits.is_empty(), digits))
+}
+
+// How the integers `a` and `b` of any size compare, if both are integers.
+fn compare_integers(a: &[u8], b: &[u8]) -> Option<Ordering> {
+    let (a_negative, a) = integer_parts(a)?;
+    let (b_negative, b) = integer_parts(b)?;
+    let magnitude = a.len().cmp(&b.len()).then_with(|| a.cmp(b));
+    Some(match (a_negative, b_negative) {
+        (false, false) => magnitude,
+        (true, true) => magnitude.reverse(),
+        (false, true) => Ordering::Greater,
+        (true, false) => Ordering::Less,
+    })
+}
+
+// How the numbers `a` and `b` compare, if both are numbers.
+fn compare_numbers(a: &[u8], b: &[u8]) -> Option<Ordering> {
+    let parse = |value| std::str::from_utf8(value).ok()?.parse::<f64>().ok();
+    parse(a)?.partial_cmp(&parse(b)?)
+}
+
+// Whether dates written in the strftime-style `format` sort as strings: ISO 8601 dates,
+// optionally followed by a 24-hour time in UTC or without a time zone.
+fn is_iso_format(format: &str) -> bool {
+    format.strip_prefix("%Y-%m-%d").is_some_and(|time| {
+        time.is_empty()
+            || ([" %H", "T%H"].iter().any(|hour| time.starts_with(hour))
+                && !["%z", "%Z", "%p"].iter().any(|spec| time.contains(spec)))
+    })
+}
+
+// The order of the values of a single field, compared as integers, as numbers and as strings.
+#[derive(Debug, Default, Clone)]
+struct OrderTally {
+    first: Option<Vec<u8>>,
+    last: Option<Vec<u8>>,
+    integer: Monotonic,
+    numeric: Monotonic,
+    lexical: Monotonic,
+}
+
+impl OrderTally {
+    // Tally the order of the values `value` then `next`.
+    fn add_pair(&mut self, value: &[u8], next: &[u8]) {
+        if value.is_empty() || next.is_empty() {
+            // empty values break the order
+            self.integer.add(None);
+            self.numeric.add(None);
+            self.lexical.add(None);
+        } else {
+            self.integer.add(compare_integers(value, next));
+            self.numeric.add(compare_numbers(value, next));
+            self.lexical.add(Some(value.cmp(next)));
+        }
+    }
+
+    fn add(&mut self, value: &[u8]) {
+        if let Some(last) = self.last.take() {
+            self.add_pair(&last, value);
+        }
+        if self.first.is_none() {
+            self.first = Some(value.to_vec());
+        }
+        self.last = Some(value.to_vec());
+    }
+
+    // Tally `value` as coming before all the values tallied so far.
+    fn add_first(&mut self, value: &[u8]) {
+        if let Some(first) = self.first.take() {
+            self.add_pair(value, &first);
+        }
+        if self.last.is_none() {
+            self.last = Some(value.to_vec());
+        }
+        self.first = Some(value.to_vec());
+    }
+}
+
+/// Running tally of the distinctness and order of the values of each field, and of the
+/// distinctness of the pairs of values of the leading fields, used to find candidate keys and
+/// sort orders.
+#[derive(Debug, Clone)]
+pub(crate) struct KeyTally {
+    n_records: usize,
+    fields: Vec<Distinct>,
+    pairs: Vec<((usize, usize), Distinct)>,
+    orders: Vec<OrderTally>,
+}
+
+impl KeyTally {
+    pub(crate) fn new(n_fields: usize) -> KeyTally {
+        let n_key_fields = n_fields.min(MAX_KEY_FIELDS);
+        KeyTally {
+            n_records: 0,
+            fields: vec![Distinct::default(); n_fields],
+            pairs: (0..n_key_fields)
+                .flat_map(|i| (i + 1..n_key_fields).map(move |j| ((i, j), Distinct::default())))
+                .collect(),
+            orders: vec![OrderTally::default(); n_fields],
+        }
+    }
+
+    pub(crate) fn add(&mut self, record: &ByteRecord) {
+        self.add_distinct(record);
+        for (i, order) in self.orders.iter_mut().enumerate() {
+            order.add(record.get(i).unwrap_or_default());
+        }
+    }
+
+    /// Tally `record` as coming before all the records tallied so far.
+    pub(crate) fn add_first(&mut self, record: &ByteRecord) {
+        self.add_distinct(record);
+        for (i, order) in self.orders.iter_mut().enumerate() {
+            order.add_first(record.get(i).unwrap_or_default());
+        }
+    }
+
+    fn add_distinct(&mut self, record: &ByteRecord) {
+        self.n_records += 1;
+        for (i, distinct) in self.fields.iter_mut().enumerate() {
+            distinct.add(&[record.get(i).unwrap_or_default()]);
+        }
+        for ((i, j), distinct) in &mut self.pairs {
+            distinct.add(&[
+                record.get(*i).unwrap_or_default(),
+                record.get(*j).unwrap_or_default(),
+            ]);
+        }
+    }
+
+    /// The fields whose values are all distinct and non-empty, then the pairs of the leading
+    /// fields whose values are, where neither field is a key by itself.
+    pub(crate) fn candidate_keys(&self) -> Vec<Vec<usize>> {
+        if self.n_records < 2 {
+            return vec![];
+        }
+        let is_key = |i: usize| !self.fields[i].repeated;
+        let fields = (0..self.fields.len())
+            .filter(|&i| is_key(i))
+            .map(|i| vec![i]);
+        let pairs = self
+            .pairs
+            .iter()
+            .filter(|((i, j), distinct)| !distinct.repeated && !is_key(*i) && !is_key(*j))
+            .map(|((i, j), _)| vec![*i, *j]);
+        fields.chain(pairs).collect()
+    }
+
+    /// The fields by which the records are sorted, given their `types` and `field_metadata`:
+    /// integer and
+    /// `Float` fields are compared as numbers, `Text` fields as strings, and `Date` and
+    /// `DateTime` fields as strings too when their dates are in the ISO 8601 format (the only
+    /// format in which dates sort as strings). Fields of other types are not reported.
+    pub(crate) fn sort_orders(
+        &self,
+        types: &[Type],
+        field_metadata: &[FieldMetadata],
+    ) -> Vec<SortOrder> {
+        if self.n_records < 2 {
+            return vec![];
+        }
+        self.orders
+            .iter()
+            .zip(types.iter().zip(field_metadata))
+            .enumerate()
+            .filter_map(|(field, (order, (ty, metadata)))| {
+                let monotonic = match ty {
+                    Type::Unsigned | Type::Signed | Type::BigInteger => order.integer,
+                    Type::Float => order.numeric,
+                    Type::Text => order.lexical,
+                    Type::Date | Type::DateTime
+                        if metadata.date_format.as_deref().is_some_and(is_iso_format) =>
+                    {
+                        order.lexical
+                    }
+                    _ => return None,
+                };
+                Some(SortOrder {
+                    field,
+                    direction: monotonic.direction()?,
+                })
+            })
+            .collect()
+    }
+}
@@ -17,16 +17,26 @@ underlying CSV input.
 This sniffer detects the following metadata about a CSV file:
 
 * Delimiter -- byte character between fields in a record
-* Has a header row? -- whether or not the first row of the data file provdes column headers
+* Has a header row? -- whether or not the first row of the data file provdes column headers, and
+  which rows repeat it further down (as in concatenated exports)
 * Number of preamble rows -- number of rows in a CSV file before the data starts (occasionally used
-in data files to introduce the data)
+  in data files to introduce the data)
 * Quote -- byte character (either ", ', or `) used to quote fields, or that the file has no quotes
 * Flexible -- whether or not records are all of the same length
 * Is utf8-encoded? -- whether the file is utf-8 encoded
 * Number of delimiter/fields -- maximum number of delimiters in each row (and therefore number of fields in
-each row)
-* Field names - the name of each field
-* Types -- the inferred data type of each field in the data table
+  each row)
+* Field names - the name of each field (generated for files without a header row), the same names
+  normalized to be safe to use as identifiers, and warnings about duplicate or empty names
+* Candidate keys -- the fields, or pairs of fields, whose values are unique in the sample
+* Sort orders -- the fields by which the sampled records are sorted
+* Index column -- whether the first field is a row index (as written by pandas or R)
+* Types -- the inferred data type of each field in the data table (or the type annotated in its
+  header, e.g. `age:int`)
+* Field metadata -- how many values of each field matched each type, the outliers that did not
+  fit the inferred type, the format of date fields, what the values represent (e.g. UUIDs, URLs
+  or zero-padded codes), whether they look like personal data, the shape of JSON and list
+  values, geospatial coordinates and geometries, and the units in their headers
 
 See [`Metadata`](metadata/struct.Metadata.html) for full information about what the sniffer returns.
 
@@ -95,7 +105,40 @@ pub use sniffer::Sniffer;
 mod sample;
 pub use sample::SampleSize;
 
+mod detector;
+pub use detector::TypeDetector;
+
 mod field_type;
-pub use field_type::{DatePreference, Type};
+pub use field_type::{
+    BooleanRules, DateOrder, DatePreference, DateRules, DateTimeZone, EpochUnit, IntegerWidth,
+    NumericSize, TextLength, Type, ZoneNotation,
+};
+
+mod geo;
+pub use geo::{Coordinate, CoordinateAxis, Geometry, GeometryFormat, GeometryType};
+
+mod header;
+pub use header::{FieldNaming, HeaderHint, HeaderRule, HeaderWarning, HintCondition};
+
+mod json;
+pub use json::{JsonKind, JsonSummary};
+
+mod keys;
+pub use keys::{SortDirection, SortOrder};
+
+mod list;
+pub use list::ListSummary;
+
+mod locale;
+pub use locale::DateLocale;
+
+mod pii;
+pub use pii::{PiiCategory, PiiMatch};
+
+mod semantic;
+pub use semantic::SemanticType;
 
 mod snip;
+
+mod unit;
+pub use unit::{HeaderUnit, UnitKind};
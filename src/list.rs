@@ -0,0 +1,76 @@
+use crate::field_type::{InferenceOptions, Type, TypeGuesses, infer_types};
+
+/// Summary of a `Text` field whose values are lists of items, joined by a secondary separator
+/// (e.g. `red|green|blue`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ListSummary {
+    /// The separator between the items of a value: `|`, `;`, or the Hive collection separator
+    /// `\x02` (`^B`).
+    pub separator: char,
+    /// The type that fits every item of every value.
+    pub element_type: Type,
+}
+
+/// The secondary separators that are recognized between list items.
+const LIST_SEPARATORS: [char; 3] = ['|', ';', '\x02'];
+
+/// Running tally of the list items in a single column, for each candidate separator.
+#[derive(Debug, Clone)]
+pub(crate) struct ListTally {
+    // number of values with several items
+    n_lists: [usize; LIST_SEPARATORS.len()],
+    // whether any value had an empty item (e.g. `a||b`)
+    empty_items: [bool; LIST_SEPARATORS.len()],
+    // intersection of the type guesses of every item
+    item_guesses: [TypeGuesses; LIST_SEPARATORS.len()],
+}
+
+impl Default for ListTally {
+    fn default() -> ListTally {
+        ListTally {
+            n_lists: [0; LIST_SEPARATORS.len()],
+            empty_items: [false; LIST_SEPARATORS.len()],
+            item_guesses: [TypeGuesses::all(); LIST_SEPARATORS.len()],
+        }
+    }
+}
+
+impl ListTally {
+    /// Add the value `s`, whose type guesses (as a whole) are `guesses`.
+    pub(crate) fn add(&mut self, s: &str, guesses: TypeGuesses, options: &InferenceOptions) {
+        for (i, &separator) in LIST_SEPARATORS.iter().enumerate() {
+            if self.empty_items[i] {
+                continue;
+            }
+            if !s.contains(separator) {
+                self.item_guesses[i] &= guesses;
+                continue;
+            }
+            self.n_lists[i] += 1;
+            for item in s.split(separator).map(str::trim) {
+                if item.is_empty() {
+                    self.empty_items[i] = true;
+                    break;
+                }
+                self.item_guesses[i] &= infer_types(item, options);
+            }
+        }
+    }
+
+    /// Summary of the lists of this column, if at least the proportion `tolerance` of its
+    /// `n_values` non-empty values hold several items separated by the same separator (and no
+    /// empty item), the rest holding a single item. When several separators qualify, the one
+    /// splitting the most values wins.
+    pub(crate) fn summary(&self, n_values: usize, tolerance: f64) -> Option<ListSummary> {
+        let threshold = tolerance * n_values as f64;
+        (0..LIST_SEPARATORS.len())
+            .filter(|&i| {
+                !self.empty_items[i] && self.n_lists[i] > 0 && self.n_lists[i] as f64 >= threshold
+            })
+            .max_by_key(|&i| self.n_lists[i])
+            .map(|i| ListSummary {
+                separator: LIST_SEPARATORS[i],
+                element_type: self.item_guesses[i].best(),
+            })
+    }
+}
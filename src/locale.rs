@@ -0,0 +1,289 @@
+use std::fmt;
+
+/// Locales whose month and weekday names are recognized in dates, when passed to
+/// `date_locales` on `Sniffer`. English names are always recognized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateLocale {
+    /// German (e.g. `3. März 2024`)
+    German,
+    /// French (e.g. `12 janv. 2023`)
+    French,
+    /// Spanish (e.g. `1 de mayo de 2022`)
+    Spanish,
+    /// Italian (e.g. `5 giugno 2021`)
+    Italian,
+    /// Portuguese (e.g. `7 de setembro de 2020`)
+    Portuguese,
+    /// Dutch (e.g. `9 maart 2019`)
+    Dutch,
+}
+
+// Month and weekday names of a locale, in lowercase. Each entry lists the full name first,
+// followed by its common abbreviations.
+struct Names {
+    months: [&'static [&'static str]; 12],
+    weekdays: [&'static [&'static str]; 7],
+    // words that are dropped from dates, e.g. "de" in "1 de mayo de 2022"
+    fillers: &'static [&'static str],
+}
+
+const GERMAN: Names = Names {
+    months: [
+        &["januar", "jänner", "jan"],
+        &["februar", "feb"],
+        &["märz", "mär", "mrz"],
+        &["april", "apr"],
+        &["mai"],
+        &["juni", "jun"],
+        &["juli", "jul"],
+        &["august", "aug"],
+        &["september", "sep", "sept"],
+        &["oktober", "okt"],
+        &["november", "nov"],
+        &["dezember", "dez"],
+    ],
+    weekdays: [
+        &["montag", "mo"],
+        &["dienstag", "di"],
+        &["mittwoch", "mi"],
+        &["donnerstag", "do"],
+        &["freitag", "fr"],
+        &["samstag", "sonnabend", "sa"],
+        &["sonntag", "so"],
+    ],
+    fillers: &[],
+};
+
+const FRENCH: Names = Names {
+    months: [
+        &["janvier", "janv", "jan"],
+        &["février", "févr", "fév", "fevrier", "fevr"],
+        &["mars"],
+        &["avril", "avr"],
+        &["mai"],
+        &["juin"],
+        &["juillet", "juil"],
+        &["août", "aout"],
+        &["septembre", "sept", "sep"],
+        &["octobre", "oct"],
+        &["novembre", "nov"],
+        &["décembre", "déc", "decembre", "dec"],
+    ],
+    weekdays: [
+        &["lundi", "lun"],
+        &["mardi", "mar"],
+        &["mercredi", "mer"],
+        &["jeudi", "jeu"],
+        &["vendredi", "ven"],
+        &["samedi", "sam"],
+        &["dimanche", "dim"],
+    ],
+    fillers: &["le", "er"],
+};
+
+const SPANISH: Names = Names {
+    months: [
+        &["enero", "ene"],
+        &["febrero", "feb"],
+        &["marzo", "mar"],
+        &["abril", "abr"],
+        &["mayo", "may"],
+        &["junio", "jun"],
+        &["julio", "jul"],
+        &["agosto", "ago"],
+        &["septiembre", "setiembre", "sep", "sept", "set"],
+        &["octubre", "oct"],
+        &["noviembre", "nov"],
+        &["diciembre", "dic"],
+    ],
+    weekdays: [
+        &["lunes", "lun"],
+        &["martes", "mar"],
+        &["miércoles", "mié", "miercoles", "mie"],
+        &["jueves", "jue"],
+        &["viernes", "vie"],
+        &["sábado", "sáb", "sabado", "sab"],
+        &["domingo", "dom"],
+    ],
+    fillers: &["de", "del"],
+};
+
+const ITALIAN: Names = Names {
+    months: [
+        &["gennaio", "gen"],
+        &["febbraio", "feb"],
+        &["marzo", "mar"],
+        &["aprile", "apr"],
+        &["maggio", "mag"],
+        &["giugno", "giu"],
+        &["luglio", "lug"],
+        &["agosto", "ago"],
+        &["settembre", "set"],
+        &["ottobre", "ott"],
+        &["novembre", "nov"],
+        &["dicembre", "dic"],
+    ],
+    weekdays: [
+        &["lunedì", "lunedi", "lun"],
+        &["martedì", "martedi", "mar"],
+        &["mercoledì", "mercoledi", "mer"],
+        &["giovedì", "giovedi", "gio"],
+        &["venerdì", "venerdi", "ven"],
+        &["sabato", "sab"],
+        &["domenica", "dom"],
+    ],
+    fillers: &[],
+};
+
+const PORTUGUESE: Names = Names {
+    months: [
+        &["janeiro", "jan"],
+        &["fevereiro", "fev"],
+        &["março", "marco", "mar"],
+        &["abril", "abr"],
+        &["maio", "mai"],
+        &["junho", "jun"],
+        &["julho", "jul"],
+        &["agosto", "ago"],
+        &["setembro", "set"],
+        &["outubro", "out"],
+        &["novembro", "nov"],
+        &["dezembro", "dez"],
+    ],
+    weekdays: [
+        &["segunda", "seg"],
+        &["terça", "terca", "ter"],
+        &["quarta", "qua"],
+        &["quinta", "qui"],
+        &["sexta", "sex"],
+        &["sábado", "sabado", "sáb", "sab"],
+        &["domingo", "dom"],
+    ],
+    fillers: &["de", "feira"],
+};
+
+const DUTCH: Names = Names {
+    months: [
+        &["januari", "jan"],
+        &["februari", "feb"],
+        &["maart", "mrt"],
+        &["april", "apr"],
+        &["mei"],
+        &["juni", "jun"],
+        &["juli", "jul"],
+        &["augustus", "aug"],
+        &["september", "sep", "sept"],
+        &["oktober", "okt"],
+        &["november", "nov"],
+        &["december", "dec"],
+    ],
+    weekdays: [
+        &["maandag", "ma"],
+        &["dinsdag", "di"],
+        &["woensdag", "wo"],
+        &["donderdag", "do"],
+        &["vrijdag", "vr"],
+        &["zaterdag", "za"],
+        &["zondag", "zo"],
+    ],
+    fillers: &[],
+};
+
+const ENGLISH_MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+const ENGLISH_WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+impl DateLocale {
+    const fn names(self) -> &'static Names {
+        match self {
+            DateLocale::German => &GERMAN,
+            DateLocale::French => &FRENCH,
+            DateLocale::Spanish => &SPANISH,
+            DateLocale::Italian => &ITALIAN,
+            DateLocale::Portuguese => &PORTUGUESE,
+            DateLocale::Dutch => &DUTCH,
+        }
+    }
+
+    /// Replace the month and weekday names of this locale in `s` with their English
+    /// abbreviations (and drop filler words), so that `s` can be read as an English date.
+    /// Returns `None` if `s` has a word that is not a month, weekday or filler of this locale.
+    fn translate(self, s: &str) -> Option<String> {
+        let names = self.names();
+        let mut translated = String::with_capacity(s.len());
+        let mut chars = s.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            if !c.is_alphabetic() {
+                translated.push(c);
+                continue;
+            }
+            let mut end = start + c.len_utf8();
+            while let Some(&(i, c)) = chars.peek() {
+                if !c.is_alphabetic() {
+                    break;
+                }
+                end = i + c.len_utf8();
+                chars.next();
+            }
+            let word = s[start..end].to_lowercase();
+            if names.fillers.contains(&word.as_str()) {
+                continue;
+            }
+            let english = if let Some(month) = lookup(&names.months, &word) {
+                ENGLISH_MONTHS[month]
+            } else if let Some(weekday) = lookup(&names.weekdays, &word) {
+                ENGLISH_WEEKDAYS[weekday]
+            } else {
+                return None;
+            };
+            translated.push_str(english);
+            // abbreviations are often followed by a period (e.g. "janv.")
+            if chars.peek().is_some_and(|&(_, c)| c == '.') {
+                chars.next();
+            }
+        }
+        // collapse the whitespace left over by dropped fillers
+        Some(translated.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+}
+
+fn lookup(table: &[&[&str]], word: &str) -> Option<usize> {
+    table.iter().position(|names| names.contains(&word))
+}
+
+/// Translate the localized month and weekday names in `s` into English, using the first of
+/// `locales` that has a name for every word in `s`. Returns the translated value, along with all
+/// of the `locales` that have a name for every word in `s`; or `None` if `s` has no words, or no
+/// locale knows all of them.
+pub(crate) fn translate_date(s: &str, locales: &[DateLocale]) -> Option<(String, Vec<DateLocale>)> {
+    if !s.chars().any(char::is_alphabetic) {
+        return None;
+    }
+    let mut translated = None;
+    let mut matched = vec![];
+    for &locale in locales {
+        if let Some(value) = locale.translate(s) {
+            translated.get_or_insert(value);
+            matched.push(locale);
+        }
+    }
+    translated.map(|value| (value, matched))
+}
+
+impl fmt::Display for DateLocale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match *self {
+                DateLocale::German => "German",
+                DateLocale::French => "French",
+                DateLocale::Spanish => "Spanish",
+                DateLocale::Italian => "Italian",
+                DateLocale::Portuguese => "Portuguese",
+                DateLocale::Dutch => "Dutch",
+            }
+        )
+    }
+}
@@ -9,7 +9,20 @@ use std::path::Path;
 use csv::{Reader, ReaderBuilder};
 use qsv_tabwriter::TabWriter;
 
-use crate::{error::Result, field_type::Type, snip::snip_preamble};
+use crate::{
+    error::Result,
+    field_type::{DateOrder, DateTimeZone, EpochUnit, IntegerWidth, NumericSize, TextLength, Type},
+    geo::{Coordinate, Geometry},
+    header::{HeaderHint, HeaderWarning},
+    json::JsonSummary,
+    keys::SortOrder,
+    list::ListSummary,
+    locale::DateLocale,
+    pii::PiiMatch,
+    semantic::SemanticType,
+    snip::snip_preamble,
+    unit::HeaderUnit,
+};
 
 /// Primary CSV metadata. Generated by
 /// [`Sniffer::sniff_path`](../struct.Sniffer.html#method.sniff_path) or
@@ -23,10 +36,34 @@ pub struct Metadata {
     pub avg_record_len: usize,
     /// (Maximum) number of fields per record.
     pub num_fields: usize,
-    /// field/column names
+    /// field/column names (generated for files without a header row, see `Sniffer::field_naming`)
     pub fields: Vec<String>,
+    /// Field names made safe to use as identifiers (e.g. SQL column names): transliterated to
+    /// ASCII, in snake_case and unique (e.g. `Unit Price (€)` becomes `unit_price_eur`).
+    pub normalized_fields: Vec<String>,
+    /// Duplicate and empty names in the header row, if any (see
+    /// [`HeaderWarning`](../enum.HeaderWarning.html)).
+    pub header_warnings: Vec<HeaderWarning>,
+    /// Line numbers (counting from 1, preamble rows included) of the rows in the sample that
+    /// repeat the header row, as in files made by concatenating several exports. These rows are
+    /// left out of type inference.
+    pub repeated_header_rows: Vec<u64>,
+    /// Whether the first field is a row index, as written by pandas or R: a field with an empty
+    /// or `Unnamed: 0` header holding strictly increasing integers (e.g. `0`, `1`, `2`, ...). Such
+    /// a field is usually left out of schemas.
+    pub has_index_column: bool,
+    /// The fields, then the pairs of fields (among the first sixteen), whose values are unique
+    /// and non-empty in the sample, and so could be used as a primary key. Pairs are only listed
+    /// when neither of their fields is a key by itself.
+    pub candidate_keys: Vec<Vec<usize>>,
+    /// The fields by which the sampled records are sorted (see
+    /// [`SortOrder`](../struct.SortOrder.html)).
+    pub sort_orders: Vec<SortOrder>,
     /// Inferred field types.
     pub types: Vec<Type>,
+    /// Details about the values examined in each field (see
+    /// [`FieldMetadata`](struct.FieldMetadata.html)).
+    pub field_metadata: Vec<FieldMetadata>,
 }
 impl fmt::Display for Metadata {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -40,12 +77,16 @@ impl fmt::Display for Metadata {
         let mut tabwtr = TabWriter::new(vec![]);
 
         for (i, ty) in self.types.iter().enumerate() {
+            let field_metadata = self.field_metadata.get(i);
             writeln!(
                 &mut tabwtr,
-                "\t{}:\t{}\t{}",
+                "\t{}:\t{}\t{}\t{}",
                 i,
                 ty,
-                self.fields.get(i).unwrap_or(&String::new())
+                self.fields.get(i).unwrap_or(&String::new()),
+                field_metadata
+                    .and_then(|field| field.date_format.as_deref())
+                    .unwrap_or_default()
             )
             .unwrap_or_default();
         }
@@ -63,6 +104,92 @@ impl fmt::Display for Metadata {
     }
 }
 
+/// Field-level metadata, describing how well the sampled values of a field fit its inferred type.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FieldMetadata {
+    /// Number of non-empty values examined.
+    pub n_values: usize,
+    /// Number of empty values examined (including values made only of whitespace).
+    pub n_empty: usize,
+    /// Number of non-empty values matching each candidate type, from narrowest to widest.
+    pub type_counts: Vec<(Type, usize)>,
+    /// Number of non-empty values that do not fit the inferred type. This can only be non-zero
+    /// when a type tolerance below 1.0 is set with
+    /// [`Sniffer::type_tolerance`](../struct.Sniffer.html#method.type_tolerance).
+    pub n_outliers: usize,
+    /// Up to five distinct example values that do not fit the inferred type.
+    pub outlier_examples: Vec<String>,
+    /// For `Date` and `DateTime` fields, the strftime-style format (e.g. `%Y-%m-%d`) that fits
+    /// every date value of the field, if there is one.
+    pub date_format: Option<String>,
+    /// For `Date` and `DateTime` fields with numeric days and months (e.g. `%d/%m/%Y`), whether
+    /// the days come first, the months come first, or the values do not tell.
+    pub date_order: Option<DateOrder>,
+    /// For `Date` and `DateTime` fields with localized month or weekday names, the locale (among
+    /// those passed to [`Sniffer::date_locales`](../struct.Sniffer.html#method.date_locales))
+    /// they were read with. The `date_format` of the field then applies to its dates once their
+    /// month and weekday names are translated into English.
+    pub date_locale: Option<DateLocale>,
+    /// The distinct special floating-point tokens (e.g. `inf`, `-Infinity` or `NaN`) among the
+    /// values of the field, whatever its type. Unless
+    /// [`Sniffer::strict_numbers`](../struct.Sniffer.html#method.strict_numbers) is set, these
+    /// count as `Float` values; otherwise they are outliers of numeric fields, which callers may
+    /// choose to read as nulls.
+    pub special_numbers: Vec<String>,
+    /// What the values of the field represent beyond their type (e.g. UUIDs or email
+    /// addresses), if at least the type tolerance of them agree. A `ZeroPaddedCode` field should
+    /// be read as text, even when its type is `Unsigned`, to keep its leading zeros.
+    pub semantic_type: Option<SemanticType>,
+    /// The header rule (among those set with
+    /// [`Sniffer::header_rules`](../struct.Sniffer.html#method.header_rules)) matching the header
+    /// of the field, if any, and whether it changed the type of the field.
+    pub header_hint: Option<HeaderHint>,
+    /// The type annotation in the header of the field (e.g. `int` for `age:int`, or `float` for
+    /// `price (float)`), if any. The annotation sets the type of the field, whatever its values,
+    /// and is left out of its name.
+    pub type_annotation: Option<String>,
+    /// The unit in the header of the field (e.g. `kg` in `Weight (kg)`), if any.
+    pub unit: Option<HeaderUnit>,
+    /// The name of the custom type of the field, if any of the
+    /// [`TypeDetector`](../trait.TypeDetector.html)s registered with
+    /// [`Sniffer::type_detector`](../struct.Sniffer.html#method.type_detector) matches at least
+    /// the type tolerance of its values, and ranks ahead of its type.
+    pub custom_type: Option<String>,
+    /// Number of non-empty values matched by each registered type detector, along with its name.
+    pub custom_type_counts: Vec<(String, usize)>,
+    /// The category of personal data (e.g. emails, phone numbers or birthdates) that the values
+    /// of the field look like, if at least half of them do, along with the proportion of them
+    /// that do.
+    pub pii: Option<PiiMatch>,
+    /// For `Text` fields whose values all are JSON documents, the kind of those documents and
+    /// the top-level keys of the objects among them.
+    pub json: Option<JsonSummary>,
+    /// For `Text` fields whose values are lists of items joined by a secondary separator (e.g.
+    /// `red|green|blue`), the separator and the type of the items.
+    pub list: Option<ListSummary>,
+    /// For `Text` fields whose values all are WKT or GeoJSON geometries, their format and
+    /// geometry type.
+    pub geometry: Option<Geometry>,
+    /// For numeric fields whose header names a latitude or longitude (e.g. `lat` or `lng`) and
+    /// whose values fit its range, the axis, along with the index of the field holding the other
+    /// coordinate of the same points.
+    pub coordinate: Option<Coordinate>,
+    /// For `Unsigned`, `Signed` and `BigInteger` fields, the narrowest integer width that fits
+    /// every value (if any does).
+    pub integer_width: Option<IntegerWidth>,
+    /// For numeric fields, the maximum number of digits before and after the decimal point, and
+    /// whether any number has an exponent.
+    pub numeric_size: Option<NumericSize>,
+    /// For `Text` fields, the maximum length of their values, in bytes and in characters.
+    pub text_length: Option<TextLength>,
+    /// For `Unsigned` and `Float` fields whose values all are plausible Unix timestamps (between
+    /// 1980 and 2100), the unit of the timestamps. Such fields are candidate `DateTime` fields.
+    pub epoch_unit: Option<EpochUnit>,
+    /// For `DateTime` fields, whether their values are naive or zone-aware, and whether they
+    /// share the same UTC offset.
+    pub datetime_zone: Option<DateTimeZone>,
+}
+
 /// Dialect-level metadata. This type encapsulates the details to be used to derive a
 /// `ReaderBuilder` object (in the [`csv`](https://docs.rs/csv) crate).
 #[derive(Clone)]
@@ -0,0 +1,194 @@
+use std::fmt;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::{
+    field_type::{TypeGuesses, fits_date_layout},
+    semantic::{is_email, semantic_type},
+};
+
+/// A category of personal data that the values of a field look like.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PiiCategory {
+    /// Email address
+    Email,
+    /// Phone number (e.g. `+1 555-123-4567` or `(020) 7946 0958`)
+    Phone,
+    /// National identification number, in the format of a US Social Security number (e.g.
+    /// `123-45-6789`)
+    NationalId,
+    /// Payment card number that passes the Luhn checksum (e.g. `4111 1111 1111 1111`)
+    CreditCard,
+    /// Date of birth (a date field whose header suggests birthdates, e.g. `dob`)
+    Birthdate,
+}
+
+impl PiiCategory {
+    // the categories that are recognized from a value alone
+    const VALUE_CATEGORIES: [PiiCategory; 4] = [
+        PiiCategory::Email,
+        PiiCategory::NationalId,
+        PiiCategory::CreditCard,
+        PiiCategory::Phone,
+    ];
+}
+
+/// The personal data category of a field, along with the proportion (between 0.0 and 1.0) of its
+/// non-empty values that fit the category.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PiiMatch {
+    /// The category of personal data.
+    pub category: PiiCategory,
+    /// Proportion of the non-empty values of the field that fit the category.
+    pub match_rate: f64,
+}
+
+/// Minimum proportion of the values of a field that have to fit a category for the field to be
+/// flagged.
+const MIN_PII_MATCH_RATE: f64 = 0.5;
+
+static SSN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\d{3})-(\d{2})-(\d{4})$").unwrap());
+static CARD_NUMBER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\d{4}([ -]?\d{2,7}){2,4}$").unwrap());
+// A `+` country code (e.g. `+44 20 7946 0958`), a parenthesised area code (e.g.
+// `(020) 7946 0958`), or the 3-3-4 (or local 3-4) grouping of North American numbers (e.g.
+// `555.123.4567` or `555-0199`).
+static PHONE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^(\+\d{1,3}[ .-]?(\(\d{1,5}\)[ .-]?)?\d{1,5}([ .-]\d{2,5})*|\(\d{1,5}\)[ .-]?\d{2,5}([ .-]\d{2,5})*|(\d{3}[ .-])?\d{3}[ .-]\d{4})$",
+    )
+    .unwrap()
+});
+static E164_PHONE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\+\d{8,15}$").unwrap());
+
+// Whether `s` is a plausible US Social Security number (no zero groups, and no area number of
+// 000, 666 or 9xx).
+fn is_national_id(s: &str) -> bool {
+    SSN.captures(s).is_some_and(|groups| {
+        let area = &groups[1];
+        area != "000"
+            && area != "666"
+            && !area.starts_with('9')
+            && &groups[2] != "00"
+            && &groups[3] != "0000"
+    })
+}
+
+// Whether `s` is a card number of 13 to 19 digits that passes the Luhn checksum.
+fn is_credit_card(s: &str) -> bool {
+    if !CARD_NUMBER.is_match(s) {
+        return false;
+    }
+    let digits: Vec<u32> = s.chars().filter_map(|c| c.to_digit(10)).collect();
+    if !(13..=19).contains(&digits.len()) {
+        return false;
+    }
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &digit)| match (i % 2, digit * 2) {
+            (1, doubled) if doubled > 9 => doubled - 9,
+            (1, doubled) => doubled,
+            _ => digit,
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+// Whether `s` is written like a phone number: 7 to 15 digits in the grouping of a phone number,
+// and not an IP address, a date or any other value with a semantic type (e.g. `192.168.100.200`
+// or `05.01.2023`).
+fn is_phone(s: &str) -> bool {
+    let n_digits = s.chars().filter(char::is_ascii_digit).count();
+    (7..=15).contains(&n_digits)
+        && PHONE.is_match(s)
+        && semantic_type(s).is_none()
+        && !fits_date_layout(s)
+}
+
+// The personal data category of the value `s`, whose type guesses are `guesses`. Numbers and
+// dates are never taken for phone numbers, except numbers in the E.164 format (a `+` then 8 to
+// 15 digits, e.g. `+15551234567`).
+fn pii_category(s: &str, guesses: TypeGuesses) -> Option<PiiCategory> {
+    if is_email(s) {
+        Some(PiiCategory::Email)
+    } else if is_national_id(s) {
+        Some(PiiCategory::NationalId)
+    } else if is_credit_card(s) {
+        Some(PiiCategory::CreditCard)
+    } else if E164_PHONE.is_match(s)
+        || (!guesses.intersects(TypeGuesses::FLOAT | TypeGuesses::DATE | TypeGuesses::DATETIME)
+            && is_phone(s))
+    {
+        Some(PiiCategory::Phone)
+    } else {
+        None
+    }
+}
+
+/// Whether the value `s` (with type guesses `guesses`) looks like personal data.
+pub(crate) fn is_pii(s: &str, guesses: TypeGuesses) -> bool {
+    pii_category(s, guesses).is_some()
+}
+
+/// Mask every letter and digit of `s` with `*`, keeping only its shape (e.g. `jane@example.com`
+/// becomes `****@*******.***`).
+pub(crate) fn redact(s: &str) -> String {
+    s.chars()
+        .map(|c| if c.is_alphanumeric() { '*' } else { c })
+        .collect()
+}
+
+/// Running tally of the personal data categories of the values in a single column.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct PiiTally {
+    counts: [usize; PiiCategory::VALUE_CATEGORIES.len()],
+}
+
+impl PiiTally {
+    pub(crate) fn add(&mut self, s: &str, guesses: TypeGuesses) {
+        if let Some(category) = pii_category(s, guesses) {
+            // safety: `pii_category` only returns categories recognized from a value alone
+            let i = PiiCategory::VALUE_CATEGORIES
+                .iter()
+                .position(|&c| c == category)
+                .unwrap();
+            self.counts[i] += 1;
+        }
+    }
+
+    /// The category that most of the `n_values` non-empty values in this column fit, if at least
+    /// `MIN_PII_MATCH_RATE` of them do.
+    pub(crate) fn pii(&self, n_values: usize) -> Option<PiiMatch> {
+        if n_values == 0 {
+            return None;
+        }
+        PiiCategory::VALUE_CATEGORIES
+            .iter()
+            .zip(self.counts)
+            .max_by_key(|&(_, count)| count)
+            .map(|(&category, count)| PiiMatch {
+                category,
+                match_rate: count as f64 / n_values as f64,
+            })
+            .filter(|pii| pii.match_rate >= MIN_PII_MATCH_RATE)
+    }
+}
+
+impl fmt::Display for PiiCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match *self {
+                PiiCategory::Email => "Email",
+                PiiCategory::Phone => "Phone",
+                PiiCategory::NationalId => "NationalId",
+                PiiCategory::CreditCard => "CreditCard",
+                PiiCategory::Birthdate => "Birthdate",
+            }
+        )
+    }
+}
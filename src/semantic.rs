@@ -0,0 +1,122 @@
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+/// What the values of a field represent, beyond their [`Type`](enum.Type.html). Reported in
+/// [`FieldMetadata`](metadata/struct.FieldMetadata.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SemanticType {
+    /// UUID (e.g. `123e4567-e89b-12d3-a456-426614174000`)
+    Uuid,
+    /// URL with a scheme (e.g. `https://example.com/path`)
+    Url,
+    /// Email address (e.g. `jane@example.com`)
+    Email,
+    /// IPv4 address (e.g. `192.168.0.1`)
+    Ipv4,
+    /// IPv6 address (e.g. `2001:db8::1`)
+    Ipv6,
+    /// Numeric code with leading zeros (e.g. the ZIP code `02134`), which has to be kept as a
+    /// string so as not to lose those zeros
+    ZeroPaddedCode,
+}
+
+impl SemanticType {
+    const ALL: [SemanticType; 6] = [
+        SemanticType::Uuid,
+        SemanticType::Url,
+        SemanticType::Email,
+        SemanticType::Ipv4,
+        SemanticType::Ipv6,
+        SemanticType::ZeroPaddedCode,
+    ];
+}
+
+static UUID: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?i)[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$").unwrap()
+});
+static URL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-zA-Z][a-zA-Z0-9+.-]*://[^\s/?#]+\S*$").unwrap());
+static EMAIL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[^\s@]+@[^\s@.]+(\.[^\s@.]+)+$").unwrap());
+
+/// Whether `s` looks like an email address.
+pub(crate) fn is_email(s: &str) -> bool {
+    EMAIL.is_match(s)
+}
+
+// The semantic type of the value `s`. Any string of digits counts as a (possibly) zero-padded
+// code; whether a field of them actually has leading zeros is tracked separately.
+pub(crate) fn semantic_type(s: &str) -> Option<SemanticType> {
+    if s.bytes().all(|b| b.is_ascii_digit()) {
+        Some(SemanticType::ZeroPaddedCode)
+    } else if UUID.is_match(s) {
+        Some(SemanticType::Uuid)
+    } else if URL.is_match(s) {
+        Some(SemanticType::Url)
+    } else if is_email(s) {
+        Some(SemanticType::Email)
+    } else if s.parse::<Ipv4Addr>().is_ok() {
+        Some(SemanticType::Ipv4)
+    } else if s.parse::<Ipv6Addr>().is_ok() {
+        Some(SemanticType::Ipv6)
+    } else {
+        None
+    }
+}
+
+/// Running tally of the semantic types of the (non-empty) values in a single column.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct SemanticTally {
+    counts: [usize; SemanticType::ALL.len()],
+    leading_zeros: bool,
+}
+
+impl SemanticTally {
+    pub(crate) fn add(&mut self, s: &str) {
+        if let Some(ty) = semantic_type(s) {
+            // safety: every semantic type is in `SemanticType::ALL`
+            let i = SemanticType::ALL.iter().position(|&t| t == ty).unwrap();
+            self.counts[i] += 1;
+            if ty == SemanticType::ZeroPaddedCode && s.len() > 1 && s.starts_with('0') {
+                self.leading_zeros = true;
+            }
+        }
+    }
+
+    /// The semantic type matched by at least `tolerance` (a proportion between 0.0 and 1.0) of
+    /// the `n_values` non-empty values in this column, if any. Strings of digits are only
+    /// zero-padded codes if at least one of them has a leading zero.
+    pub(crate) fn semantic_type(&self, n_values: usize, tolerance: f64) -> Option<SemanticType> {
+        if n_values == 0 {
+            return None;
+        }
+        let threshold = tolerance * n_values as f64;
+        SemanticType::ALL
+            .iter()
+            .zip(self.counts)
+            .filter(|&(_, count)| count > 0 && count as f64 >= threshold)
+            .max_by_key(|&(_, count)| count)
+            .map(|(&ty, _)| ty)
+            .filter(|&ty| ty != SemanticType::ZeroPaddedCode || self.leading_zeros)
+    }
+}
+
+impl fmt::Display for SemanticType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match *self {
+                SemanticType::Uuid => "UUID",
+                SemanticType::Url => "URL",
+                SemanticType::Email => "Email",
+                SemanticType::Ipv4 => "IPv4",
+                SemanticType::Ipv6 => "IPv6",
+                SemanticType::ZeroPaddedCode => "ZeroPaddedCode",
+            }
+        )
+    }
+}
@@ -3,6 +3,7 @@ use std::cell::RefCell;
 use std::fs::File;
 use std::io::{Read, Seek, SeekFrom};
 use std::path::Path;
+use std::sync::Arc;
 
 use csv::{Reader, StringRecord};
 use csv_core as csvc;
@@ -10,11 +11,20 @@ use regex::Regex;
 
 use crate::{
     chain::{Chain, STATE_STEADYFLEX, STATE_STEADYSTRICT, STATE_UNSTEADY, ViterbiResults},
+    detector::TypeDetector,
     error::{Result, SnifferError},
     field_type::{
-        DatePreference, Type, TypeGuesses, get_best_types, infer_record_types, infer_types,
+        BooleanRules, DatePreference, DateRules, InferenceOptions, Type, TypeTally, get_best_types,
+        infer_record_types,
     },
-    metadata::{Dialect, Header, Metadata, Quote},
+    geo::{pair_coordinates, unnamed_coordinates},
+    header::{
+        FieldNaming, HeaderRule, header_warnings, is_index_header, normalize_fields,
+        suggested_name, type_annotation,
+    },
+    keys::{KeyTally, SortOrder},
+    locale::DateLocale,
+    metadata::{Dialect, FieldMetadata, Header, Metadata, Quote},
     sample::{SampleIter, SampleSize, take_sample_from_start},
     snip::snip_preamble,
 };
@@ -35,17 +45,52 @@ pub struct Sniffer {
     flexible: Option<bool>,
     is_utf8: Option<bool>,
 
+    // whether the CSV file has a header row, as specified (as opposed to guessed)
+    header_row_setting: Option<bool>,
+
     // Metadata guesses
     delimiter_freq: Option<usize>,
     fields: Vec<String>,
+    repeated_header_rows: Vec<u64>,
+    has_index_column: bool,
+    candidate_keys: Vec<Vec<usize>>,
+    sort_orders: Vec<SortOrder>,
     types: Vec<Type>,
+    field_metadata: Vec<FieldMetadata>,
     avg_record_len: Option<usize>,
 
     // sample size to sniff
     sample_size: Option<SampleSize>,
 
+    // proportion of values that have to match a type for a field to be of that type
+    type_tolerance: Option<f64>,
+
+    // vocabulary of boolean fields
+    boolean_rules: Option<BooleanRules>,
+
+    // whether numbers have to follow the strict numeric grammar
+    strict_numbers: Option<bool>,
+
+    // whether to mask example values that look like personal data
+    redact_pii: Option<bool>,
+
+    // detectors of user-defined types
+    type_detectors: Vec<Arc<dyn TypeDetector>>,
+
+    // rules of thumb for typing fields from their header
+    header_rules: Option<Vec<HeaderRule>>,
+
+    // naming of the fields of files without a header row
+    field_naming: Option<FieldNaming>,
+
     // date format preference
     date_preference: Option<DatePreference>,
+
+    // locales of the month and weekday names in dates
+    date_locales: Vec<DateLocale>,
+
+    // rules of thumb for date typing
+    date_rules: Option<DateRules>,
 }
 impl Sniffer {
     /// Create a new CSV sniffer.
@@ -61,6 +106,7 @@ impl Sniffer {
     pub fn header(&mut self, header: &Header) -> &mut Sniffer {
         self.num_preamble_rows = Some(header.num_preamble_rows);
         self.has_header_row = Some(header.has_header_row);
+        self.header_row_setting = Some(header.has_header_row);
         self
     }
     /// Specify the quote character (if any), and whether two quotes in a row as to be interepreted
@@ -83,17 +129,143 @@ impl Sniffer {
         self.sample_size.unwrap_or(SampleSize::Bytes(1 << 14))
     }
 
-    /// The date format preference when sniffing.
+    /// The proportion (between 0.0 and 1.0) of non-empty values in a field that have to match a
+    /// type for the field to be inferred as that type. The values that do not match are reported
+    /// as outliers in [`FieldMetadata`](metadata/struct.FieldMetadata.html).
+    ///
+    /// The tolerance only comes into play when no type but `Text` matches every value: a type
+    /// that every value matches is never traded for a narrower one that only some of them do.
+    /// Tolerances above 1.0 count as 1.0, and tolerances of 0.0 or below as the smallest positive
+    /// one (a single matching value is enough). A `NaN` tolerance is ignored.
+    ///
+    /// The type tolerance defaults to 1.0 (every value has to match).
+    pub fn type_tolerance(&mut self, tolerance: f64) -> &mut Sniffer {
+        if !tolerance.is_nan() {
+            self.type_tolerance = Some(tolerance.clamp(f64::MIN_POSITIVE, 1.0));
+        }
+        self
+    }
+
+    fn get_type_tolerance(&self) -> f64 {
+        self.type_tolerance.unwrap_or(1.0)
+    }
+
+    /// The values read as true and false in `Boolean` fields, whether a field needs both a true
+    /// and a false value to be typed `Boolean`, and whether fields of `0`s and `1`s are typed
+    /// `Boolean` or `Unsigned`.
+    ///
+    /// The boolean rules default to `BooleanRules::default()` (`t`/`f`, `y`/`n`, `true`/`false`,
+    /// `yes`/`no` and `1`/`0`, with a single truth value being enough).
+    pub fn boolean_rules(&mut self, rules: BooleanRules) -> &mut Sniffer {
+        self.boolean_rules = Some(rules);
+        self
+    }
+
+    /// Whether values have to follow a strict numeric grammar (an optional sign, then digits with
+    /// an optional decimal point, e.g. `-1.5`) to be typed as `Float`. Without it, the exponents
+    /// (e.g. `1e5`) and the special tokens (`inf`, `infinity` and `NaN`, in any case) accepted
+    /// by Rust's float parser also count as floats. Either way, the special tokens found in a field
+    /// are reported in [`FieldMetadata`](metadata/struct.FieldMetadata.html).
+    ///
+    /// Strict numbers default to `false`.
+    pub fn strict_numbers(&mut self, strict: bool) -> &mut Sniffer {
+        self.strict_numbers = Some(strict);
+        self
+    }
+
+    /// Whether to mask the example values reported in
+    /// [`FieldMetadata`](metadata/struct.FieldMetadata.html) (replacing their letters and digits
+    /// with `*`) when they look like personal data, or belong to a field flagged as personal
+    /// data.
+    ///
+    /// Redacting personal data defaults to `false`.
+    pub fn redact_pii(&mut self, redact: bool) -> &mut Sniffer {
+        self.redact_pii = Some(redact);
+        self
+    }
+
+    /// Register a detector of a user-defined type, evaluated on every value alongside the
+    /// built-in types. The custom type of each field is reported in
+    /// [`FieldMetadata`](metadata/struct.FieldMetadata.html); see
+    /// [`TypeDetector::priority`](trait.TypeDetector.html#method.priority) for how it ranks
+    /// against the built-in types.
+    ///
+    /// No type detectors are registered by default.
+    pub fn type_detector<D: TypeDetector + 'static>(&mut self, detector: D) -> &mut Sniffer {
+        self.type_detectors.push(Arc::new(detector));
+        self
+    }
+
+    /// The rules of thumb that make fields lean toward a type based on their header (e.g. `is_*`
+    /// fields toward `Boolean`). For each field, the first rule matching its header applies if
+    /// its condition holds. The rule and its influence are reported in
+    /// [`FieldMetadata`](metadata/struct.FieldMetadata.html). Pass `HeaderRule::defaults()` for
+    /// the built-in rules.
+    ///
+    /// There are no header rules by default: fields are typed from their values alone.
+    pub fn header_rules(&mut self, rules: Vec<HeaderRule>) -> &mut Sniffer {
+        self.header_rules = Some(rules);
+        self
+    }
+
+    /// How to name the fields of a file without a header row (e.g. `field_1`, `field_2`, ...),
+    /// whose names are reported in [`Metadata`](metadata/struct.Metadata.html) as if they were
+    /// read from a header. With semantic naming, a latitude field followed by a longitude field
+    /// is also reported as a pair of coordinates, since there is no header to tell them apart.
+    ///
+    /// The field naming defaults to `FieldNaming::default()` (`field_{n}`, without semantic
+    /// names).
+    pub fn field_naming(&mut self, naming: FieldNaming) -> &mut Sniffer {
+        self.field_naming = Some(naming);
+        self
+    }
+
+    /// The date format preference when sniffing. The order of days and months is inferred for
+    /// each field from its values (see
+    /// [`FieldMetadata::date_order`](metadata/struct.FieldMetadata.html#structfield.date_order)),
+    /// so this preference only breaks the tie for fields where every date fits both orders.
     ///
     /// The date format preference defaults to `DatePreference::MDY`.
     pub fn date_preference(&mut self, date_preference: DatePreference) -> &mut Sniffer {
-        DATE_PREFERENCE.with(|preference| {
-            *preference.borrow_mut() = date_preference;
-        });
         self.date_preference = Some(date_preference);
         self
     }
 
+    /// The locales whose month and weekday names (e.g. `3 März 2024` or `1 de mayo de 2022`) are
+    /// recognized in dates, in addition to English. The locale a date field was read with is
+    /// reported in [`FieldMetadata`](metadata/struct.FieldMetadata.html); when several locales
+    /// fit, the first one is reported.
+    ///
+    /// By default, only English names are recognized.
+    pub fn date_locales(&mut self, locales: &[DateLocale]) -> &mut Sniffer {
+        self.date_locales = locales.to_vec();
+        self
+    }
+
+    /// The rules of thumb that the values of a field have to pass for it to be typed as a
+    /// `Date` or `DateTime`: a plausible year range, a minimum number of date components, and
+    /// whether the separators between those components have to be consistent.
+    ///
+    /// The date rules default to `DateRules::default()` (years 1800 to 2200, at least three
+    /// components, consistent separators).
+    pub fn date_rules(&mut self, rules: DateRules) -> &mut Sniffer {
+        self.date_rules = Some(rules);
+        self
+    }
+
+    fn inference_options(&self) -> InferenceOptions {
+        InferenceOptions {
+            type_tolerance: self.get_type_tolerance(),
+            boolean_rules: self.boolean_rules.clone().unwrap_or_default(),
+            date_locales: self.date_locales.clone(),
+            date_rules: self.date_rules.unwrap_or_default(),
+            strict_numbers: self.strict_numbers.unwrap_or(false),
+            redact_pii: self.redact_pii.unwrap_or(false),
+            type_detectors: self.type_detectors.clone(),
+            header_rules: self.header_rules.clone().unwrap_or_default(),
+        }
+    }
+
     /// Sniff the CSV file located at the provided path, and return a `Reader` (from the
     /// [`csv`](https://docs.rs/csv) crate) ready to ready the file.
     ///
@@ -128,6 +300,10 @@ impl Sniffer {
         IS_UTF8.with(|flag| {
             *flag.borrow_mut() = true;
         });
+        // init DATE_PREFERENCE global var to this sniffer's preference
+        DATE_PREFERENCE.with(|preference| {
+            *preference.borrow_mut() = self.date_preference.unwrap_or(DatePreference::MdyFormat);
+        });
         // guess quotes & delim
         self.infer_quotes_delim(&mut reader)?;
 
@@ -183,7 +359,21 @@ impl Sniffer {
             avg_record_len: self.avg_record_len.unwrap(),
             num_fields: self.delimiter_freq.unwrap() + 1,
             fields: self.fields.clone(),
+            normalized_fields: normalize_fields(
+                &self.fields,
+                &self.field_naming.clone().unwrap_or_default(),
+            ),
+            repeated_header_rows: self.repeated_header_rows.clone(),
+            has_index_column: self.has_index_column,
+            candidate_keys: self.candidate_keys.clone(),
+            sort_orders: self.sort_orders.clone(),
+            header_warnings: if self.has_header_row.unwrap() {
+                header_warnings(&self.fields)
+            } else {
+                vec![]
+            },
             types: self.types.clone(),
+            field_metadata: self.field_metadata.clone(),
         })
     }
 
@@ -386,7 +576,16 @@ impl Sniffer {
         // safety: unwrap is safe as we just checked that delimiter_freq is Some
         let field_count = self.delimiter_freq.unwrap() + 1;
 
+        // whether the file has a header row, if specified
+        let known_header_row = self.header_row_setting;
         let mut csv_reader = self.create_csv_reader(reader)?;
+        let headers = csv_reader.byte_headers()?.clone();
+        let num_preamble_rows = self.num_preamble_rows.unwrap_or(0) as u64;
+        self.fields.clear();
+        self.repeated_header_rows.clear();
+        self.has_index_column = false;
+        self.candidate_keys.clear();
+        self.sort_orders.clear();
         let mut records_iter = csv_reader.byte_records();
         let mut n_bytes = 0;
         let mut n_records = 0;
@@ -394,13 +593,13 @@ impl Sniffer {
 
         // Infer types for the top row. We'll save this set of types to check against the types
         // of the remaining rows to see if this is part of the data or a separate header row.
-        let header_row_types = match records_iter.next() {
+        let header_row = match records_iter.next() {
             Some(record) => {
                 let byte_record = record?;
                 let str_record = StringRecord::from_byte_record_lossy(byte_record);
                 n_records += 1;
                 n_bytes += count_bytes(&str_record);
-                infer_record_types(&str_record)
+                str_record
             }
             None => {
                 return Err(SnifferError::SniffingFailed(
@@ -408,13 +607,38 @@ impl Sniffer {
                 ));
             }
         };
-        let mut row_types = vec![TypeGuesses::all(); field_count];
+        let options = self.inference_options();
+        let header_row_types = infer_record_types(&header_row, &options);
+        // the last value of the first field, while its values are strictly increasing integers
+        let mut last_index = header_row
+            .get(0)
+            .and_then(|field| field.parse::<i128>().ok());
+        let mut tallies = vec![TypeTally::default(); field_count];
+        let mut keys = KeyTally::new(field_count);
+        keys.add(header_row.as_byte_record());
+        // records equal to the header row, set aside until we know whether it is one
+        let mut repeated_headers = vec![];
 
         for record in records_iter {
             let record = record?;
-            for (i, field) in record.iter().enumerate() {
-                let str_field = String::from_utf8_lossy(field).to_string();
-                row_types[i] &= infer_types(&str_field);
+            if known_header_row != Some(false) && record == headers {
+                // the header row repeated further down (e.g. in concatenated exports), which is
+                // left out of the tallies
+                let line = record.position().map_or(0, |pos| pos.line());
+                self.repeated_header_rows.push(line + num_preamble_rows);
+                repeated_headers.push(record.clone());
+            } else {
+                for (i, field) in record.iter().enumerate() {
+                    let str_field = String::from_utf8_lossy(field);
+                    tallies[i].add(&str_field, &options);
+                }
+                keys.add(&record);
+                if let Some(last) = last_index {
+                    let index = record
+                        .get(0)
+                        .and_then(|field| String::from_utf8_lossy(field).parse::<i128>().ok());
+                    last_index = index.filter(|&index| index > last);
+                }
             }
             n_records += 1;
             n_bytes += record.as_slice().len();
@@ -433,34 +657,143 @@ impl Sniffer {
                 SampleSize::All => {}
             }
         }
-        if n_records == 1 {
+        if n_records == 1 && known_header_row != Some(true) {
             // there's only one row in the whole data file (the top row already parsed),
-            // so we're going to assume it's a data row, not a header row.
+            // so we're going to assume it's a data row, not a header row, unless we were told
+            // the file has one.
             self.has_header_row = Some(false);
             self.types = get_best_types(&header_row_types);
+            let mut tallies = vec![TypeTally::default(); field_count];
+            for (tally, field) in tallies.iter_mut().zip(header_row.iter()) {
+                tally.add(field, &options);
+            }
+            self.field_metadata = tallies
+                .iter()
+                .zip(&self.types)
+                .map(|(tally, ty)| tally.field_metadata(*ty, None, &options))
+                .collect();
+            self.name_fields(&tallies);
             self.avg_record_len = Some(n_bytes);
             return Ok(());
         }
 
-        if header_row_types
-            .iter()
-            .zip(&row_types)
-            .any(|(header, data)| !data.allows(*header))
-        {
+        let has_header_row = known_header_row.unwrap_or_else(|| {
+            header_row_types
+                .iter()
+                .zip(tallies.iter().map(|tally| tally.guesses(&options)))
+                .any(|(header, data)| !data.allows(*header))
+        });
+        // The reader takes the header row (if any) out of the records, so the top row examined
+        // above is a data row.
+        for (tally, field) in tallies.iter_mut().zip(header_row.iter()) {
+            tally.add(field, &options);
+        }
+        if !has_header_row {
+            // without a header row, rows equal to the header row are data rows after all (tallying
+            // them last rules out any sort order, which is at worst too cautious)
+            self.repeated_header_rows.clear();
+            for record in &repeated_headers {
+                for (tally, field) in tallies.iter_mut().zip(record.iter()) {
+                    tally.add(&String::from_utf8_lossy(field), &options);
+                }
+                keys.add(record);
+            }
+            if known_header_row.is_none() {
+                // the row the reader took out as the header row is the first data row
+                for (tally, field) in tallies.iter_mut().zip(headers.iter()) {
+                    tally.add(&String::from_utf8_lossy(field), &options);
+                }
+                keys.add_first(&headers);
+            }
+        }
+        let mut annotations = vec![];
+        if has_header_row {
             self.has_header_row = Some(true);
-            // get field names in header
+            // get field names in header, without their type annotations
             for field in csv_reader.byte_headers()? {
-                self.fields.push(String::from_utf8_lossy(field).to_string());
+                let name = String::from_utf8_lossy(field).to_string();
+                let annotation = type_annotation(&name);
+                self.fields.push(
+                    annotation
+                        .as_ref()
+                        .map_or(name, |annotation| annotation.name.clone()),
+                );
+                annotations.push(annotation);
             }
+            // a row index, as written by pandas or R
+            self.has_index_column = last_index.is_some()
+                && self
+                    .fields
+                    .first()
+                    .is_some_and(|name| is_index_header(name));
         } else {
             self.has_header_row = Some(false);
         }
 
+        let row_types: Vec<_> = tallies
+            .iter()
+            .map(|tally| tally.guesses(&options))
+            .collect();
         self.types = get_best_types(&row_types);
+        let header_hints: Vec<_> = tallies
+            .iter()
+            .zip(self.types.iter_mut())
+            .enumerate()
+            .map(|(i, (tally, ty))| {
+                if let Some(Some(annotation)) = annotations.get(i) {
+                    // type annotations are authoritative
+                    *ty = annotation.ty;
+                    return None;
+                }
+                let hint = tally.header_hint(self.fields.get(i)?, *ty, &options)?;
+                if hint.applied {
+                    *ty = hint.rule.hint;
+                }
+                Some(hint)
+            })
+            .collect();
+        self.field_metadata = tallies
+            .iter()
+            .zip(&self.types)
+            .zip(header_hints)
+            .enumerate()
+            .map(|(i, ((tally, ty), header_hint))| FieldMetadata {
+                header_hint,
+                type_annotation: annotations
+                    .get(i)
+                    .and_then(|annotation| Some(annotation.as_ref()?.annotation.clone())),
+                ..tally.field_metadata(*ty, self.fields.get(i).map(String::as_str), &options)
+            })
+            .collect();
+        pair_coordinates(&self.fields, &mut self.field_metadata);
+        self.candidate_keys = keys.candidate_keys();
+        self.sort_orders = keys.sort_orders(&self.types, &self.field_metadata);
+        if self.has_header_row == Some(false) {
+            self.name_fields(&tallies);
+        }
         self.avg_record_len = Some(n_bytes / n_records);
         Ok(())
     }
 
+    // Generate the names of the fields of a file without a header row.
+    fn name_fields(&mut self, tallies: &[TypeTally]) {
+        let naming = self.field_naming.clone().unwrap_or_default();
+        if naming.semantic {
+            let ranges: Vec<_> = tallies.iter().map(TypeTally::numeric_range).collect();
+            let coordinates = unnamed_coordinates(&self.types, &ranges);
+            for (metadata, coordinate) in self.field_metadata.iter_mut().zip(coordinates) {
+                metadata.coordinate = metadata.coordinate.or(coordinate);
+            }
+        }
+        let suggestions: Vec<_> = self
+            .types
+            .iter()
+            .zip(&self.field_metadata)
+            .map(|(ty, metadata)| suggested_name(*ty, metadata))
+            .collect();
+        self.fields = naming.names(&suggestions);
+    }
+
     fn create_csv_reader<'a, R: Read + Seek>(
         &self,
         mut reader: &'a mut R,
@@ -474,7 +807,7 @@ impl Sniffer {
         if let Some(delim) = self.delimiter {
             builder.delimiter(delim);
         }
-        if let Some(has_header_row) = self.has_header_row {
+        if let Some(has_header_row) = self.header_row_setting {
             builder.has_headers(has_header_row);
         }
         match self.quote {
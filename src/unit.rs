@@ -0,0 +1,197 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::{field_type::Type, header::header_words};
+
+/// The kind of quantity measured in a unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitKind {
+    /// Mass (e.g. `kg` or `lb`)
+    Mass,
+    /// Length (e.g. `km` or `ft`)
+    Length,
+    /// Area (e.g. `m²`)
+    Area,
+    /// Volume (e.g. `L`)
+    Volume,
+    /// Temperature (e.g. `°C`)
+    Temperature,
+    /// Duration (e.g. `s` or `h`)
+    Time,
+    /// Speed (e.g. `km/h`)
+    Speed,
+    /// Energy (e.g. `kWh`)
+    Energy,
+    /// Power (e.g. `kW`)
+    Power,
+    /// Amount of money, with its ISO 4217 currency code as symbol (e.g. `USD`)
+    Currency,
+    /// Percentage (`%`)
+    Percentage,
+}
+
+/// The unit in the header of a field (e.g. `kg` in `Weight (kg)`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderUnit {
+    /// The header without its unit (e.g. `Weight` for `Weight (kg)`).
+    pub base_name: String,
+    /// The unit as written in the header (e.g. `current US$`).
+    pub unit: String,
+    /// The standard symbol of the unit, if recognized (e.g. `°C` for `[degC]`, or the currency
+    /// code `USD` for `(current US$)`).
+    pub symbol: Option<String>,
+    /// The kind of quantity measured in the unit, if recognized.
+    pub kind: Option<UnitKind>,
+}
+
+// Recognized units: the ways they are written, their standard symbol and their kind. Units that
+// are also common words or single letters (e.g. `in`, `m` or `C`) are only recognized between
+// brackets.
+const UNITS: [(&[&str], &str, UnitKind); 40] = [
+    (&["kg", "kilograms"], "kg", UnitKind::Mass),
+    (&["g", "grams"], "g", UnitKind::Mass),
+    (&["mg", "milligrams"], "mg", UnitKind::Mass),
+    (&["t", "tonnes", "tons"], "t", UnitKind::Mass),
+    (&["lb", "lbs", "pounds"], "lb", UnitKind::Mass),
+    (&["oz", "ounces"], "oz", UnitKind::Mass),
+    (&["km", "kilometers", "kilometres"], "km", UnitKind::Length),
+    (&["m", "meters", "metres"], "m", UnitKind::Length),
+    (
+        &["cm", "centimeters", "centimetres"],
+        "cm",
+        UnitKind::Length,
+    ),
+    (
+        &["mm", "millimeters", "millimetres"],
+        "mm",
+        UnitKind::Length,
+    ),
+    (&["mi", "miles"], "mi", UnitKind::Length),
+    (&["ft", "feet"], "ft", UnitKind::Length),
+    (&["in", "inches"], "in", UnitKind::Length),
+    (&["m2", "m²", "sqm"], "m²", UnitKind::Area),
+    (&["km2", "km²"], "km²", UnitKind::Area),
+    (&["ha", "hectares"], "ha", UnitKind::Area),
+    (&["l", "liters", "litres"], "L", UnitKind::Volume),
+    (
+        &["ml", "milliliters", "millilitres"],
+        "mL",
+        UnitKind::Volume,
+    ),
+    (
+        &["°c", "ºc", "c", "degc", "celsius"],
+        "°C",
+        UnitKind::Temperature,
+    ),
+    (
+        &["°f", "ºf", "f", "degf", "fahrenheit"],
+        "°F",
+        UnitKind::Temperature,
+    ),
+    (&["k", "kelvin"], "K", UnitKind::Temperature),
+    (&["s", "sec", "secs", "seconds"], "s", UnitKind::Time),
+    (&["ms", "milliseconds"], "ms", UnitKind::Time),
+    (&["min", "mins", "minutes"], "min", UnitKind::Time),
+    (&["h", "hr", "hrs", "hours"], "h", UnitKind::Time),
+    (&["d", "days"], "d", UnitKind::Time),
+    (&["km/h", "kmh", "kph"], "km/h", UnitKind::Speed),
+    (&["mph"], "mph", UnitKind::Speed),
+    (&["m/s"], "m/s", UnitKind::Speed),
+    (&["kwh"], "kWh", UnitKind::Energy),
+    (&["j", "joules"], "J", UnitKind::Energy),
+    (&["w", "watts"], "W", UnitKind::Power),
+    (&["kw", "kilowatts"], "kW", UnitKind::Power),
+    (&["mw", "megawatts"], "MW", UnitKind::Power),
+    (&["%", "percent", "pct"], "%", UnitKind::Percentage),
+    (&["$", "us$", "usd", "dollars"], "USD", UnitKind::Currency),
+    (&["€", "eur", "euros"], "EUR", UnitKind::Currency),
+    (&["£", "gbp"], "GBP", UnitKind::Currency),
+    (&["¥", "jpy", "yen"], "JPY", UnitKind::Currency),
+    (
+        &["chf", "cad", "aud", "cny", "inr", "brl", "mxn"],
+        "",
+        UnitKind::Currency,
+    ),
+];
+
+// Units recognized as the last word of a header without brackets (e.g. `distance_km`).
+const SUFFIX_UNITS: [&str; 13] = [
+    "kg", "mg", "lbs", "km", "cm", "mm", "ms", "kmh", "mph", "kwh", "pct", "usd", "eur",
+];
+
+// `name (unit)` or `name [unit]` (e.g. `Weight (kg)` or `Temp [°C]`).
+static BRACKETED_UNIT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?P<name>.*?)\s*(\((?P<paren>[^()]+)\)|\[(?P<bracket>[^\[\]]+)\])$").unwrap()
+});
+
+// The standard symbol and kind of the unit written `unit`, if recognized. Units of several words
+// (e.g. `current US$`) are recognized by the first word that is a unit.
+fn recognize(unit: &str) -> Option<(String, UnitKind)> {
+    let lookup = |word: &str| {
+        let word = word.to_lowercase();
+        UNITS.iter().find_map(|(spellings, symbol, kind)| {
+            spellings.contains(&word.as_str()).then(|| {
+                // currency codes without a common symbol are their own symbol
+                let symbol = if symbol.is_empty() {
+                    word.to_uppercase()
+                } else {
+                    symbol.to_string()
+                };
+                (symbol, *kind)
+            })
+        })
+    };
+    lookup(unit.trim()).or_else(|| unit.split_whitespace().find_map(lookup))
+}
+
+/// The unit in the header `name` of a field of type `ty`: a recognized unit between brackets or
+/// as the last word of the header (e.g. `Weight (kg)`, `Temp [°C]` or `distance_km`), or any
+/// other bracketed text in the header of a numeric field (other than a number, e.g. a year).
+/// Units of a single letter (e.g. `Height (m)`) are only recognized in numeric fields.
+pub(crate) fn header_unit(name: &str, ty: Type) -> Option<HeaderUnit> {
+    if let Some(caps) = BRACKETED_UNIT.captures(name) {
+        let base_name = caps.name("name")?.as_str();
+        let unit = caps
+            .name("paren")
+            .or_else(|| caps.name("bracket"))?
+            .as_str()
+            .trim();
+        let recognized = recognize(unit);
+        let numeric = matches!(
+            ty,
+            Type::Unsigned | Type::Signed | Type::BigInteger | Type::Float
+        );
+        let is_number = unit.chars().all(|c| c.is_ascii_digit() || c == '.');
+        // single letters (e.g. the `S` of `size (S)`) are only units of numeric fields
+        let is_letter = unit.chars().count() == 1 && unit.chars().all(char::is_alphabetic);
+        if base_name.is_empty() || ((recognized.is_none() || is_letter) && (!numeric || is_number))
+        {
+            return None;
+        }
+        return Some(HeaderUnit {
+            base_name: base_name.to_string(),
+            unit: unit.to_string(),
+            symbol: recognized.as_ref().map(|(symbol, _)| symbol.clone()),
+            kind: recognized.map(|(_, kind)| kind),
+        });
+    }
+    let words = header_words(name);
+    let (last, rest) = words.split_last()?;
+    if rest.is_empty() || !SUFFIX_UNITS.contains(&last.as_str()) {
+        return None;
+    }
+    let (symbol, kind) = recognize(last)?;
+    let end = name.len().checked_sub(last.len())?;
+    if !name.is_char_boundary(end) || !name[end..].eq_ignore_ascii_case(last) {
+        return None;
+    }
+    Some(HeaderUnit {
+        base_name: name[..end]
+            .trim_end_matches(|c: char| !c.is_alphanumeric())
+            .to_string(),
+        unit: name[end..].to_string(),
+        symbol: Some(symbol),
+        kind: Some(kind),
+    })
+}
@@ -6,16 +6,31 @@ use std::path::Path;
 use qsv_sniffer::metadata::*;
 use qsv_sniffer::{SampleSize, Sniffer, Type};
 
+// Take the field metadata, which is too long to spell out, out of `metadata`: the tests below
+// compare the rest of the metadata as a whole, and check a few values of each field.
+fn take_field_metadata(metadata: &mut Metadata) -> Vec<FieldMetadata> {
+    std::mem::take(&mut metadata.field_metadata)
+}
+
+// The numbers of non-empty and empty values of each field.
+fn value_counts(field_metadata: &[FieldMetadata]) -> Vec<(usize, usize)> {
+    field_metadata
+        .iter()
+        .map(|field| (field.n_values, field.n_empty))
+        .collect()
+}
+
 #[test]
 fn test_semicolon() {
     let data_filepath = Path::new(file!())
         .parent()
         .unwrap()
         .join("data/2016_presidential_election_durham.csv");
-    let metadata = Sniffer::new()
+    let mut metadata = Sniffer::new()
         .sample_size(SampleSize::All)
         .sniff_path(data_filepath)
         .unwrap();
+    let field_metadata = take_field_metadata(&mut metadata);
     assert_eq!(
         metadata,
         Metadata {
@@ -38,15 +53,32 @@ fn test_semicolon() {
                 "Voting Method".to_string(),
                 "Voting Preinct".to_string()
             ],
+            normalized_fields: vec![
+                "name".to_string(),
+                "party".to_string(),
+                "vote_count".to_string(),
+                "voting_method".to_string(),
+                "voting_preinct".to_string()
+            ],
+            header_warnings: vec![],
+            repeated_header_rows: vec![],
+            has_index_column: false,
+            candidate_keys: vec![],
+            sort_orders: vec![],
             types: vec![
                 Type::Text,
                 Type::Text,
                 Type::Unsigned,
                 Type::Text,
                 Type::Text
-            ]
+            ],
+            field_metadata: vec![],
         }
     );
+    assert_eq!(
+        value_counts(&field_metadata),
+        vec![(1505, 0), (903, 602), (1505, 0), (1505, 0), (1505, 0)]
+    );
 }
 
 #[test]
@@ -55,10 +87,11 @@ fn test_comma() {
         .parent()
         .unwrap()
         .join("data/library-visitors.csv");
-    let metadata = Sniffer::new()
+    let mut metadata = Sniffer::new()
         .sample_size(SampleSize::All)
         .sniff_path(data_filepath)
         .unwrap();
+    let field_metadata = take_field_metadata(&mut metadata);
     assert_eq!(
         metadata,
         Metadata {
@@ -81,15 +114,32 @@ fn test_comma() {
                 "Catalog Visits".to_string(),
                 "Overdrive Visits".to_string()
             ],
+            normalized_fields: vec![
+                "month".to_string(),
+                "door_count".to_string(),
+                "web_site_visits".to_string(),
+                "catalog_visits".to_string(),
+                "overdrive_visits".to_string()
+            ],
+            header_warnings: vec![],
+            repeated_header_rows: vec![],
+            has_index_column: false,
+            candidate_keys: vec![vec![0], vec![1], vec![2], vec![3], vec![4]],
+            sort_orders: vec![],
             types: vec![
                 Type::Text,
                 Type::Unsigned,
                 Type::Unsigned,
                 Type::Unsigned,
                 Type::Unsigned
-            ]
+            ],
+            field_metadata: vec![],
         }
     );
+    assert_eq!(
+        value_counts(&field_metadata),
+        vec![(12, 0), (12, 0), (12, 0), (12, 0), (12, 0)]
+    );
 }
 
 #[test]
@@ -98,10 +148,11 @@ fn test_boolean() {
         .parent()
         .unwrap()
         .join("data/library-visitors-boolean.csv");
-    let metadata = Sniffer::new()
+    let mut metadata = Sniffer::new()
         .sample_size(SampleSize::All)
         .sniff_path(data_filepath)
         .unwrap();
+    let field_metadata = take_field_metadata(&mut metadata);
     assert_eq!(
         metadata,
         Metadata {
@@ -125,6 +176,19 @@ fn test_boolean() {
                 "Overdrive Visits".to_string(),
                 "Vacation".to_string()
             ],
+            normalized_fields: vec![
+                "month".to_string(),
+                "door_count".to_string(),
+                "web_site_visits".to_string(),
+                "catalog_visits".to_string(),
+                "overdrive_visits".to_string(),
+                "vacation".to_string()
+            ],
+            header_warnings: vec![],
+            repeated_header_rows: vec![],
+            has_index_column: false,
+            candidate_keys: vec![vec![0], vec![1], vec![2], vec![3], vec![4]],
+            sort_orders: vec![],
             types: vec![
                 Type::Text,
                 Type::Unsigned,
@@ -132,9 +196,14 @@ fn test_boolean() {
                 Type::Unsigned,
                 Type::Unsigned,
                 Type::Boolean
-            ]
+            ],
+            field_metadata: vec![],
         }
     );
+    assert_eq!(
+        value_counts(&field_metadata),
+        vec![(12, 0), (12, 0), (12, 0), (12, 0), (12, 0), (12, 0)]
+    );
 }
 
 #[test]
@@ -143,10 +212,11 @@ fn test_flexible() {
         .parent()
         .unwrap()
         .join("data/gotriangle-routes-cary-ch-duke-durham-raleigh-wofline.csv");
-    let metadata = Sniffer::new()
+    let mut metadata = Sniffer::new()
         .sample_size(SampleSize::All)
         .sniff_path(data_filepath)
         .unwrap();
+    let field_metadata = take_field_metadata(&mut metadata);
     assert_eq!(
         metadata,
         Metadata {
@@ -171,6 +241,20 @@ fn test_flexible() {
                 "min_headway_minutes".to_string(),
                 "route_url".to_string()
             ],
+            normalized_fields: vec![
+                "municipality".to_string(),
+                "agency_id".to_string(),
+                "route_id".to_string(),
+                "route_short_name".to_string(),
+                "route_long_name".to_string(),
+                "min_headway_minutes".to_string(),
+                "route_url".to_string()
+            ],
+            header_warnings: vec![],
+            repeated_header_rows: vec![],
+            has_index_column: false,
+            candidate_keys: vec![vec![2], vec![3, 4]],
+            sort_orders: vec![],
             types: vec![
                 Type::Text,
                 Type::Unsigned,
@@ -179,9 +263,22 @@ fn test_flexible() {
                 Type::Text,
                 Type::Unsigned,
                 Type::Text
-            ]
+            ],
+            field_metadata: vec![],
         }
     );
+    assert_eq!(
+        value_counts(&field_metadata),
+        vec![
+            (131, 0),
+            (131, 0),
+            (131, 0),
+            (131, 0),
+            (131, 0),
+            (130, 1),
+            (120, 0)
+        ]
+    );
 }
 
 #[test]
@@ -190,10 +287,11 @@ fn test_utf8_again() {
         .parent()
         .unwrap()
         .join("data/test-utf8.csv");
-    let metadata = Sniffer::new()
+    let mut metadata = Sniffer::new()
         .sample_size(SampleSize::All)
         .sniff_path(data_filepath)
         .unwrap();
+    let field_metadata = take_field_metadata(&mut metadata);
     assert_eq!(
         metadata,
         Metadata {
@@ -222,6 +320,24 @@ fn test_utf8_again() {
                 "NUM.DDE".to_string(),
                 "NUM.RE".to_string()
             ],
+            normalized_fields: vec![
+                "dia_desemb".to_string(),
+                "cod_subitem_ncm".to_string(),
+                "vmle_dolar_bal_exp".to_string(),
+                "peso_liq_merc_bal_exp".to_string(),
+                "cod_impdr_expdr".to_string(),
+                "nome_impdr_expdr".to_string(),
+                "pais_origem_destino".to_string(),
+                "ua_local_desbq_embq".to_string(),
+                "nome_importador_estrangeiro".to_string(),
+                "num_dde".to_string(),
+                "num_re".to_string()
+            ],
+            header_warnings: vec![],
+            repeated_header_rows: vec![],
+            has_index_column: false,
+            candidate_keys: vec![vec![2], vec![3], vec![10]],
+            sort_orders: vec![],
             types: vec![
                 Type::Text,
                 Type::Unsigned,
@@ -234,9 +350,26 @@ fn test_utf8_again() {
                 Type::Text,
                 Type::Text,
                 Type::Text
-            ]
+            ],
+            field_metadata: vec![],
         }
     );
+    assert_eq!(
+        value_counts(&field_metadata),
+        vec![
+            (9, 0),
+            (9, 0),
+            (9, 0),
+            (9, 0),
+            (9, 0),
+            (9, 0),
+            (9, 0),
+            (9, 0),
+            (9, 0),
+            (9, 0),
+            (9, 0)
+        ]
+    );
 }
 
 #[test]
@@ -245,10 +378,11 @@ fn test_date_sniffing() {
         .parent()
         .unwrap()
         .join("data/boston311.csv");
-    let metadata = Sniffer::new()
+    let mut metadata = Sniffer::new()
         .sample_size(SampleSize::All)
         .sniff_path(data_filepath)
         .unwrap();
+    let field_metadata = take_field_metadata(&mut metadata);
     assert_eq!(
         metadata,
         Metadata {
@@ -295,6 +429,50 @@ fn test_date_sniffing() {
                 "longitude".to_string(),
                 "source".to_string(),
             ],
+            normalized_fields: vec![
+                "case_enquiry_id".to_string(),
+                "open_dt".to_string(),
+                "target_dt".to_string(),
+                "closed_dt".to_string(),
+                "ontime".to_string(),
+                "case_status".to_string(),
+                "closure_reason".to_string(),
+                "case_title".to_string(),
+                "subject".to_string(),
+                "reason".to_string(),
+                "type".to_string(),
+                "queue".to_string(),
+                "department".to_string(),
+                "submittedphoto".to_string(),
+                "closedphoto".to_string(),
+                "location".to_string(),
+                "fire_district".to_string(),
+                "pwd_district".to_string(),
+                "city_council_district".to_string(),
+                "police_district".to_string(),
+                "neighborhood".to_string(),
+                "neighborhood_services_district".to_string(),
+                "ward".to_string(),
+                "precinct".to_string(),
+                "location_street_name".to_string(),
+                "location_zipcode".to_string(),
+                "latitude".to_string(),
+                "longitude".to_string(),
+                "source".to_string()
+            ],
+            header_warnings: vec![],
+            repeated_header_rows: vec![],
+            has_index_column: false,
+            candidate_keys: vec![
+                vec![0],
+                vec![1],
+                vec![15],
+                vec![6, 7],
+                vec![6, 8],
+                vec![6, 9],
+                vec![6, 10]
+            ],
+            sort_orders: vec![],
             types: vec![
                 Type::Unsigned,
                 Type::DateTime,
@@ -325,7 +503,80 @@ fn test_date_sniffing() {
                 Type::Float,
                 Type::Float,
                 Type::Text
-            ]
+            ],
+            field_metadata: vec![],
         }
     );
+    assert_eq!(
+        value_counts(&field_metadata),
+        vec![
+            (25, 0),
+            (25, 0),
+            (20, 5),
+            (20, 5),
+            (25, 0),
+            (25, 0),
+            (20, 5),
+            (25, 0),
+            (25, 0),
+            (25, 0),
+            (25, 0),
+            (25, 0),
+            (25, 0),
+            (9, 16),
+            (0, 25),
+            (24, 1),
+            (24, 1),
+            (24, 1),
+            (24, 1),
+            (24, 1),
+            (24, 1),
+            (24, 1),
+            (24, 1),
+            (24, 1),
+            (24, 1),
+            (21, 4),
+            (25, 0),
+            (25, 0),
+            (25, 0)
+        ]
+    );
+    let date_formats: Vec<Option<&str>> = field_metadata
+        .iter()
+        .map(|field| field.date_format.as_deref())
+        .collect();
+    assert_eq!(
+        date_formats,
+        vec![
+            None,
+            Some("%Y-%m-%d %H:%M:%S"),
+            Some("%Y-%m-%d %H:%M:%S"),
+            Some("%Y-%m-%d %H:%M:%S"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None
+        ]
+    );
 }
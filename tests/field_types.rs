@@ -0,0 +1,1377 @@
+extern crate qsv_sniffer;
+
+use std::path::Path;
+
+use qsv_sniffer::metadata::{Header, Quote};
+use qsv_sniffer::{
+    BooleanRules, Coordinate, CoordinateAxis, DateLocale, DateOrder, DatePreference, DateRules,
+    DateTimeZone, EpochUnit, FieldNaming, Geometry, GeometryFormat, GeometryType, HeaderRule,
+    HeaderWarning, HintCondition, IntegerWidth, JsonKind, ListSummary, NumericSize, PiiCategory,
+    SampleSize, SemanticType, Sniffer, SortDirection, SortOrder, TextLength, Type, TypeDetector,
+    UnitKind, ZoneNotation,
+};
+
+#[test]
+fn test_type_tolerance() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/sensor-readings.csv");
+
+    // a single "n/a" turns the readings into text
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(metadata.types[1], Type::Text);
+    assert_eq!(metadata.field_metadata[1].n_values, 20);
+    assert_eq!(metadata.field_metadata[1].n_outliers, 0);
+    assert!(
+        metadata.field_metadata[1]
+            .type_counts
+            .contains(&(Type::Unsigned, 19))
+    );
+
+    // ...unless we tolerate a few outliers
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .type_tolerance(0.9)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.types,
+        vec![Type::Text, Type::Unsigned, Type::Text, Type::Unsigned]
+    );
+    assert_eq!(metadata.field_metadata[1].n_outliers, 1);
+    assert_eq!(
+        metadata.field_metadata[1].outlier_examples,
+        vec!["n/a".to_string()]
+    );
+
+    // counts that are mostly 0 or 1 are not taken for booleans, since they all fit `Unsigned`
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .type_tolerance(0.6)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(metadata.types[3], Type::Unsigned);
+    assert_eq!(metadata.field_metadata[3].n_outliers, 0);
+
+    // a tolerance of zero still takes a matching value, and a NaN one is ignored
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .type_tolerance(0.0)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.fields,
+        vec!["station", "reading", "status", "retries"]
+    );
+    assert_eq!(
+        metadata.types,
+        vec![Type::Text, Type::Unsigned, Type::Text, Type::Unsigned]
+    );
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .type_tolerance(f64::NAN)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(metadata.types[1], Type::Text);
+}
+
+#[test]
+fn test_date_formats() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/date-formats.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.types,
+        vec![
+            Type::Unsigned,
+            Type::Date,
+            Type::Date,
+            Type::Date,
+            Type::DateTime
+        ]
+    );
+    let date_formats: Vec<Option<&str>> = metadata
+        .field_metadata
+        .iter()
+        .map(|field| field.date_format.as_deref())
+        .collect();
+    assert_eq!(
+        date_formats,
+        vec![
+            None,
+            Some("%Y-%m-%d"),
+            Some("%d.%m.%Y"),
+            Some("%d %b %Y"),
+            Some("%Y-%m-%dT%H:%M:%SZ")
+        ]
+    );
+}
+
+#[test]
+fn test_date_orders() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/date-orders.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.types,
+        vec![Type::Unsigned, Type::Date, Type::Date, Type::Date]
+    );
+    let date_orders: Vec<Option<DateOrder>> = metadata
+        .field_metadata
+        .iter()
+        .map(|field| field.date_order)
+        .collect();
+    assert_eq!(
+        date_orders,
+        vec![
+            None,
+            Some(DateOrder::Mdy),
+            Some(DateOrder::Dmy),
+            Some(DateOrder::Ambiguous)
+        ]
+    );
+    assert_eq!(
+        metadata.field_metadata[2].date_format.as_deref(),
+        Some("%d/%m/%Y")
+    );
+    assert_eq!(
+        metadata.field_metadata[3].date_format.as_deref(),
+        Some("%m/%d/%Y")
+    );
+
+    // the date preference only breaks ties
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .date_preference(DatePreference::DmyFormat)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.field_metadata[1].date_format.as_deref(),
+        Some("%m/%d/%Y")
+    );
+    assert_eq!(
+        metadata.field_metadata[3].date_format.as_deref(),
+        Some("%d/%m/%Y")
+    );
+}
+
+#[test]
+fn test_date_locales() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/localized-dates.csv");
+
+    // only English month names are recognized by default
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.types,
+        vec![Type::Unsigned, Type::Text, Type::Text, Type::Text]
+    );
+
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .date_locales(&[DateLocale::German, DateLocale::French, DateLocale::Spanish])
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.types,
+        vec![Type::Unsigned, Type::Date, Type::Date, Type::Date]
+    );
+    let date_locales: Vec<Option<DateLocale>> = metadata
+        .field_metadata
+        .iter()
+        .map(|field| field.date_locale)
+        .collect();
+    assert_eq!(
+        date_locales,
+        vec![
+            None,
+            Some(DateLocale::German),
+            Some(DateLocale::French),
+            Some(DateLocale::Spanish)
+        ]
+    );
+    // German dates, with a dot after the day, are read in their translated form
+    assert_eq!(
+        metadata.field_metadata[1].date_format.as_deref(),
+        Some("%d. %b %Y")
+    );
+    assert_eq!(
+        metadata.field_metadata[2].date_format.as_deref(),
+        Some("%d %b %Y")
+    );
+}
+
+#[test]
+fn test_date_locales_abbreviated() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/test-utf8.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .date_locales(&[DateLocale::German])
+        .sniff_path(data_filepath)
+        .unwrap();
+    assert_eq!(metadata.types[0], Type::Date);
+    assert_eq!(
+        metadata.field_metadata[0].date_locale,
+        Some(DateLocale::German)
+    );
+    assert_eq!(
+        metadata.field_metadata[0].date_format.as_deref(),
+        Some("%d %b %Y")
+    );
+}
+
+#[test]
+fn test_epoch_timestamps() {
+    let data_filepath = Path::new(file!()).parent().unwrap().join("data/events.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(data_filepath)
+        .unwrap();
+    let epoch_units: Vec<Option<EpochUnit>> = metadata
+        .field_metadata
+        .iter()
+        .map(|field| field.epoch_unit)
+        .collect();
+    assert_eq!(
+        epoch_units,
+        vec![
+            None,
+            Some(EpochUnit::Seconds),
+            Some(EpochUnit::Milliseconds),
+            None,
+            // ten-digit numbers are only timestamps if the header says so
+            None
+        ]
+    );
+}
+
+#[test]
+fn test_datetime_zones() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/zoned-datetimes.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(data_filepath)
+        .unwrap();
+    assert_eq!(metadata.types[1..], [Type::DateTime; 7]);
+    let zones: Vec<DateTimeZone> = metadata.field_metadata[1..]
+        .iter()
+        .map(|field| field.datetime_zone.clone().unwrap())
+        .collect();
+    assert_eq!(
+        zones,
+        vec![
+            DateTimeZone {
+                notations: vec![ZoneNotation::Naive],
+                offset: None
+            },
+            DateTimeZone {
+                notations: vec![ZoneNotation::Z],
+                offset: Some(0)
+            },
+            DateTimeZone {
+                notations: vec![ZoneNotation::Offset],
+                offset: Some(3600)
+            },
+            DateTimeZone {
+                notations: vec![ZoneNotation::Offset, ZoneNotation::Z],
+                offset: None
+            },
+            DateTimeZone {
+                notations: vec![ZoneNotation::Name],
+                offset: None
+            },
+            DateTimeZone {
+                notations: vec![ZoneNotation::Name],
+                offset: Some(-4 * 3600)
+            },
+            // EDT shares its offset with -04:00
+            DateTimeZone {
+                notations: vec![ZoneNotation::Name, ZoneNotation::Offset],
+                offset: Some(-4 * 3600)
+            },
+        ]
+    );
+    assert!(zones[0].is_naive());
+    assert!(zones[1..].iter().all(DateTimeZone::is_aware));
+}
+
+#[test]
+fn test_date_rules() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/date-rules.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.types,
+        vec![
+            Type::Unsigned,
+            Type::Unsigned,
+            Type::Float,
+            Type::Text,
+            Type::Text
+        ]
+    );
+    // numbers are not counted as dates, even though the date parser reads them as timestamps
+    for field in &metadata.field_metadata[1..3] {
+        assert!(field.type_counts.contains(&(Type::Date, 0)));
+        assert!(field.type_counts.contains(&(Type::DateTime, 0)));
+    }
+
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .date_rules(DateRules {
+            min_year: 1500,
+            consistent_separator: false,
+            ..DateRules::default()
+        })
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(metadata.types[3..], [Type::Date, Type::Date]);
+}
+
+#[test]
+fn test_boolean_rules() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/booleans.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.types,
+        vec![
+            Type::Unsigned,
+            Type::Boolean,
+            Type::Text,
+            Type::Boolean,
+            Type::Boolean
+        ]
+    );
+
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .boolean_rules(BooleanRules {
+            true_values: vec!["oui".into(), "yes".into()],
+            false_values: vec!["non".into(), "no".into()],
+            require_both: true,
+            zero_one: false,
+        })
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.types,
+        vec![
+            Type::Unsigned,
+            Type::Unsigned,
+            Type::Boolean,
+            // a single truth value is not enough
+            Type::Text,
+            Type::Text
+        ]
+    );
+}
+
+#[test]
+fn test_integer_widths() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/integers.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.types,
+        vec![
+            Type::Unsigned,
+            Type::Unsigned,
+            Type::Signed,
+            // one value overflows u64, but fits in u128
+            Type::BigInteger,
+            Type::Signed,
+            Type::BigInteger
+        ]
+    );
+    let integer_widths: Vec<Option<IntegerWidth>> = metadata
+        .field_metadata
+        .iter()
+        .map(|field| field.integer_width)
+        .collect();
+    assert_eq!(
+        integer_widths,
+        vec![
+            Some(IntegerWidth::Bits8),
+            Some(IntegerWidth::Bits8),
+            Some(IntegerWidth::Bits8),
+            Some(IntegerWidth::Bits128),
+            Some(IntegerWidth::Bits32),
+            // some account numbers overflow 128 bits
+            None
+        ]
+    );
+}
+
+#[test]
+fn test_numeric_sizes_and_text_lengths() {
+    let data_filepath = Path::new(file!()).parent().unwrap().join("data/sizes.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.types,
+        vec![
+            Type::Unsigned,
+            Type::Float,
+            Type::Float,
+            Type::Text,
+            Type::Unsigned
+        ]
+    );
+
+    let price = metadata.field_metadata[1].numeric_size.unwrap();
+    assert_eq!((price.precision(), price.scale()), (6, 3));
+    assert!(price.is_exact_decimal());
+    let ratio = metadata.field_metadata[2].numeric_size.unwrap();
+    assert!(!ratio.is_exact_decimal());
+    // leading zeros do not count
+    assert_eq!(
+        metadata.field_metadata[4].numeric_size,
+        Some(NumericSize {
+            integer_digits: 2,
+            fraction_digits: 0,
+            has_exponent: false
+        })
+    );
+
+    assert_eq!(metadata.field_metadata[1].text_length, None);
+    assert_eq!(
+        metadata.field_metadata[3].text_length,
+        Some(TextLength {
+            max_bytes: 15,
+            max_chars: 12
+        })
+    );
+}
+
+#[test]
+fn test_strict_numbers() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/special-numbers.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.types,
+        vec![Type::Unsigned, Type::Float, Type::Float, Type::Float]
+    );
+    assert_eq!(
+        metadata.field_metadata[1].special_numbers,
+        vec!["NaN".to_string(), "-inf".to_string()]
+    );
+
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .strict_numbers(true)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    // exponents do not follow the strict grammar either
+    assert_eq!(
+        metadata.types,
+        vec![Type::Unsigned, Type::Text, Type::Text, Type::Text]
+    );
+    assert!(metadata.field_metadata[3].special_numbers.is_empty());
+    assert_eq!(
+        metadata.field_metadata[2].special_numbers,
+        vec![
+            "Inf".to_string(),
+            "inf".to_string(),
+            "NaN".to_string(),
+            "Infinity".to_string(),
+            "nan".to_string()
+        ]
+    );
+
+    // the special tokens are the only outliers of the readings
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .strict_numbers(true)
+        .type_tolerance(0.5)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(metadata.types[1], Type::Float);
+    assert_eq!(
+        metadata.field_metadata[1].outlier_examples,
+        metadata.field_metadata[1].special_numbers
+    );
+}
+
+#[test]
+fn test_semantic_types() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/identifiers.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    let semantic_types: Vec<Option<SemanticType>> = metadata
+        .field_metadata
+        .iter()
+        .map(|field| field.semantic_type)
+        .collect();
+    assert_eq!(
+        semantic_types,
+        vec![
+            None,
+            Some(SemanticType::Uuid),
+            Some(SemanticType::Url),
+            Some(SemanticType::Email),
+            Some(SemanticType::Ipv4),
+            Some(SemanticType::Ipv6),
+            Some(SemanticType::ZeroPaddedCode),
+            // numbers without leading zeros are just numbers
+            None
+        ]
+    );
+    // zip codes are still typed as numbers, but are flagged to be kept as strings
+    assert_eq!(metadata.types[6], Type::Unsigned);
+}
+
+#[test]
+fn test_pii() {
+    let data_filepath = Path::new(file!()).parent().unwrap().join("data/people.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .type_tolerance(0.8)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    let pii: Vec<Option<PiiCategory>> = metadata
+        .field_metadata
+        .iter()
+        .map(|field| field.pii.map(|pii| pii.category))
+        .collect();
+    assert_eq!(
+        pii,
+        vec![
+            None,
+            Some(PiiCategory::Email),
+            Some(PiiCategory::Phone),
+            Some(PiiCategory::NationalId),
+            Some(PiiCategory::CreditCard),
+            Some(PiiCategory::Birthdate),
+            None
+        ]
+    );
+    let phone = metadata.field_metadata[2].pii.unwrap();
+    assert!((phone.match_rate - 6.0 / 7.0).abs() < 1e-9);
+    assert_eq!(metadata.types[6], Type::Unsigned);
+    assert_eq!(
+        metadata.field_metadata[6].outlier_examples,
+        vec!["+1 555-987-6543".to_string()]
+    );
+
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .type_tolerance(0.8)
+        .redact_pii(true)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.field_metadata[6].outlier_examples,
+        vec!["+* ***-***-****".to_string()]
+    );
+
+    // IP addresses, dotted dates, version strings and order numbers are not phone numbers
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(data_filepath.with_file_name("not-phones.csv"))
+        .unwrap();
+    assert_eq!(
+        metadata.field_metadata[1].semantic_type,
+        Some(SemanticType::Ipv4)
+    );
+    assert!(
+        metadata
+            .field_metadata
+            .iter()
+            .all(|field| field.pii.is_none())
+    );
+}
+
+#[test]
+fn test_pii_e164_phones() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/e164-phones.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    // phone numbers in the E.164 format are flagged, though they parse as numbers
+    assert_eq!(metadata.types[1], Type::Unsigned);
+    let phone = metadata.field_metadata[1].pii.unwrap();
+    assert_eq!(phone.category, PiiCategory::Phone);
+    assert!((phone.match_rate - 1.0).abs() < 1e-9);
+    assert!(metadata.field_metadata[0].pii.is_none());
+}
+
+#[test]
+fn test_json_fields() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/documents.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(data_filepath)
+        .unwrap();
+    assert_eq!(metadata.num_fields, 5);
+    assert_eq!(metadata.types[1..], [Type::Text; 4]);
+    let kinds: Vec<Option<JsonKind>> = metadata
+        .field_metadata
+        .iter()
+        .map(|field| field.json.as_ref().map(|json| json.kind))
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            None,
+            Some(JsonKind::Object),
+            Some(JsonKind::Array),
+            Some(JsonKind::Mixed),
+            None
+        ]
+    );
+    assert_eq!(
+        metadata.field_metadata[1].json.as_ref().unwrap().keys,
+        vec!["address", "age", "email", "name"]
+    );
+}
+
+#[test]
+fn test_list_fields() {
+    let data_filepath = Path::new(file!()).parent().unwrap().join("data/lists.csv");
+    // every value has to hold several items by default
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .delimiter(b',')
+        .sniff_path(&data_filepath)
+        .unwrap();
+    let lists: Vec<Option<ListSummary>> = metadata
+        .field_metadata
+        .iter()
+        .map(|field| field.list)
+        .collect();
+    assert_eq!(
+        lists,
+        vec![
+            None,
+            Some(ListSummary {
+                separator: '|',
+                element_type: Type::Text
+            }),
+            None,
+            None,
+            None
+        ]
+    );
+
+    // ...unless we tolerate a few values holding a single item
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .delimiter(b',')
+        .type_tolerance(0.8)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(metadata.types[1..], [Type::Text; 4]);
+    let lists: Vec<Option<ListSummary>> = metadata
+        .field_metadata
+        .iter()
+        .map(|field| field.list)
+        .collect();
+    assert_eq!(
+        lists,
+        vec![
+            None,
+            Some(ListSummary {
+                separator: '|',
+                element_type: Type::Text
+            }),
+            Some(ListSummary {
+                separator: ';',
+                element_type: Type::Unsigned
+            }),
+            Some(ListSummary {
+                separator: '\x02',
+                element_type: Type::Text
+            }),
+            // a single value with a separator does not make a list
+            None
+        ]
+    );
+}
+
+#[test]
+fn test_geospatial_fields() {
+    let data_filepath = Path::new(file!()).parent().unwrap().join("data/geo.csv");
+    // the quoted GeoJSON holds as many `":"` as `","`, which leaves the dialect ambiguous
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .delimiter(b',')
+        .quote(Quote::Some(b'"'))
+        .sniff_path(data_filepath)
+        .unwrap();
+    let coordinates: Vec<Option<Coordinate>> = metadata
+        .field_metadata
+        .iter()
+        .map(|field| field.coordinate)
+        .collect();
+    let coordinate = |axis, pair| {
+        Some(Coordinate {
+            axis,
+            pair: Some(pair),
+        })
+    };
+    assert_eq!(
+        coordinates,
+        vec![
+            None,
+            // coordinates with the same prefix pair up, even when others are nearer
+            coordinate(CoordinateAxis::Latitude, 3),
+            coordinate(CoordinateAxis::Longitude, 4),
+            coordinate(CoordinateAxis::Longitude, 1),
+            coordinate(CoordinateAxis::Latitude, 2),
+            None,
+            None,
+            None
+        ]
+    );
+    assert_eq!(
+        metadata.field_metadata[5].geometry,
+        Some(Geometry {
+            format: GeometryFormat::Wkt,
+            geometry_type: GeometryType::Point
+        })
+    );
+    assert_eq!(
+        metadata.field_metadata[6].geometry,
+        Some(Geometry {
+            format: GeometryFormat::GeoJson,
+            geometry_type: GeometryType::Polygon
+        })
+    );
+}
+
+#[derive(Debug)]
+struct IsinDetector;
+
+impl TypeDetector for IsinDetector {
+    fn name(&self) -> &str {
+        "ISIN"
+    }
+
+    fn detect(&self, value: &str) -> bool {
+        let bytes = value.as_bytes();
+        bytes.len() == 12
+            && bytes[..2].iter().all(u8::is_ascii_uppercase)
+            && bytes[2..11]
+                .iter()
+                .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+            && bytes[11].is_ascii_digit()
+    }
+}
+
+#[derive(Debug)]
+struct SkuDetector;
+
+impl TypeDetector for SkuDetector {
+    fn name(&self) -> &str {
+        "SKU"
+    }
+
+    fn detect(&self, value: &str) -> bool {
+        value.len() == 6 && value.bytes().all(|b| b.is_ascii_digit())
+    }
+
+    fn priority(&self) -> Type {
+        Type::Unsigned
+    }
+}
+
+#[derive(Debug)]
+struct AlphanumericDetector;
+
+impl TypeDetector for AlphanumericDetector {
+    fn name(&self) -> &str {
+        "Alphanumeric"
+    }
+
+    fn detect(&self, value: &str) -> bool {
+        value.chars().all(char::is_alphanumeric)
+    }
+}
+
+#[test]
+fn test_type_detectors() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/instruments.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .type_detector(IsinDetector)
+        .type_detector(SkuDetector)
+        .type_detector(AlphanumericDetector)
+        .sniff_path(data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.types,
+        vec![Type::Unsigned, Type::Text, Type::Unsigned, Type::Text]
+    );
+    let custom_types: Vec<Option<&str>> = metadata
+        .field_metadata
+        .iter()
+        .map(|field| field.custom_type.as_deref())
+        .collect();
+    assert_eq!(
+        custom_types,
+        vec![
+            // every id is alphanumeric, but that does not rank ahead of `Unsigned`
+            None,
+            // the first detector registered wins among those of equal priority
+            Some("ISIN"),
+            Some("SKU"),
+            Some("Alphanumeric")
+        ]
+    );
+    assert_eq!(
+        metadata.field_metadata[1].custom_type_counts,
+        vec![
+            ("ISIN".to_string(), 5),
+            ("SKU".to_string(), 0),
+            ("Alphanumeric".to_string(), 5)
+        ]
+    );
+}
+
+#[test]
+fn test_header_rules() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/header-hints.csv");
+    let boolean_rules = BooleanRules {
+        require_both: true,
+        ..BooleanRules::default()
+    };
+    // fields are typed from their values alone by default
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .boolean_rules(boolean_rules.clone())
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.types,
+        vec![
+            Type::Unsigned,
+            Type::Unsigned,
+            Type::Text,
+            Type::Unsigned,
+            Type::NULL,
+            Type::Unsigned,
+            Type::NULL,
+            Type::Unsigned,
+            Type::NULL
+        ]
+    );
+    assert!(
+        metadata
+            .field_metadata
+            .iter()
+            .all(|field| field.header_hint.is_none())
+    );
+
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .boolean_rules(boolean_rules)
+        .header_rules(
+            HeaderRule::defaults()
+                .into_iter()
+                .chain([HeaderRule::new("notes", Type::Text, HintCondition::Fits)])
+                .collect(),
+        )
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.types,
+        vec![
+            Type::Unsigned,
+            // zip codes of different lengths (some lost their leading zero) lean toward text
+            Type::Text,
+            Type::Boolean,
+            // ids with leading zeros lean toward text
+            Type::Text,
+            Type::Date,
+            Type::Float,
+            Type::Text,
+            Type::Unsigned,
+            // so do zip codes that are all empty
+            Type::Text
+        ]
+    );
+    let hints: Vec<Option<(&str, bool)>> = metadata
+        .field_metadata
+        .iter()
+        .map(|field| {
+            field
+                .header_hint
+                .as_ref()
+                .map(|hint| (hint.rule.pattern.as_str(), hint.applied))
+        })
+        .collect();
+    assert_eq!(
+        hints,
+        vec![
+            // ids without leading zeros stay numbers
+            Some(("id", false)),
+            Some(("zip", true)),
+            Some(("is_*", true)),
+            Some(("*_id", true)),
+            Some(("*_date", true)),
+            Some(("*_amount", true)),
+            Some(("notes", true)),
+            // zip rules only match whole words
+            None,
+            Some(("*_zip", true))
+        ]
+    );
+    assert_eq!(
+        metadata.field_metadata[2]
+            .header_hint
+            .as_ref()
+            .unwrap()
+            .value_type,
+        Type::Text
+    );
+}
+
+#[test]
+fn test_field_naming() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/headerless.csv");
+    let header = Header {
+        num_preamble_rows: 0,
+        has_header_row: false,
+    };
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .header(&header)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert!(!metadata.dialect.header.has_header_row);
+    // without a header row, the top row is tallied along with the others
+    assert_eq!(metadata.field_metadata[0].n_values, 6);
+    assert_eq!(
+        metadata.fields,
+        vec![
+            "field_1", "field_2", "field_3", "field_4", "field_5", "field_6"
+        ]
+    );
+    assert_eq!(metadata.field_metadata[3].coordinate, None);
+
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .header(&header)
+        .field_naming(FieldNaming::new("col{i}", false))
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.fields,
+        vec!["col0", "col1", "col2", "col3", "col4", "col5"]
+    );
+
+    // fields whose values tell what they represent are named after it
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .header(&header)
+        .field_naming(FieldNaming::new("field_{n}", true))
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.fields,
+        vec![
+            "field_1",
+            "email",
+            "date",
+            "latitude",
+            "longitude",
+            "field_6"
+        ]
+    );
+    assert_eq!(
+        metadata.field_metadata[3].coordinate,
+        Some(Coordinate {
+            axis: CoordinateAxis::Latitude,
+            pair: Some(4)
+        })
+    );
+}
+
+#[test]
+fn test_sniffer_reuse() {
+    let data_dir = Path::new(file!()).parent().unwrap().join("data");
+    let mut sniffer = Sniffer::new();
+    sniffer.sample_size(SampleSize::All);
+    let metadata = sniffer
+        .sniff_path(data_dir.join("headerless-repeat.csv"))
+        .unwrap();
+    assert!(!metadata.dialect.header.has_header_row);
+    assert_eq!(metadata.fields, vec!["field_1", "field_2"]);
+
+    // what was guessed about one file is not taken for a setting on the next
+    let metadata = sniffer.sniff_path(data_dir.join("people.csv")).unwrap();
+    assert!(metadata.dialect.header.has_header_row);
+    assert_eq!(
+        metadata.fields,
+        vec!["id", "email", "phone", "ssn", "card", "dob", "ref"]
+    );
+    let again = sniffer.sniff_path(data_dir.join("people.csv")).unwrap();
+    assert_eq!(again, metadata);
+}
+
+#[test]
+fn test_header_normalization() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/messy-headers.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert!(metadata.dialect.header.has_header_row);
+    // the original names are kept as they are
+    assert_eq!(metadata.fields[6], "Caf\u{e9}\u{a0}Name");
+    assert_eq!(
+        metadata.normalized_fields,
+        vec![
+            "id",
+            "first_name",
+            "first_name_2",
+            "field_4",
+            "unit_price_eur",
+            "_2020_sales",
+            "cafe_name",
+            "id_2"
+        ]
+    );
+    // `First Name` and `first_name` normalize to the same name, and `id` and `id` followed by a
+    // zero-width space look the same
+    assert_eq!(
+        metadata.header_warnings,
+        vec![
+            HeaderWarning::Duplicate {
+                name: "id".to_string(),
+                fields: vec![0, 7]
+            },
+            HeaderWarning::Duplicate {
+                name: "First Name".to_string(),
+                fields: vec![1, 2]
+            },
+            HeaderWarning::Empty { field: 3 }
+        ]
+    );
+}
+
+#[test]
+fn test_single_record_with_header() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/single-record.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .header(&Header {
+            num_preamble_rows: 0,
+            has_header_row: true,
+        })
+        .sniff_path(&data_filepath)
+        .unwrap();
+    // the header row we specified is kept, though there is a single record
+    assert!(metadata.dialect.header.has_header_row);
+    assert_eq!(metadata.fields, vec!["name", "age"]);
+    assert_eq!(metadata.types, vec![Type::Text, Type::Signed]);
+    assert_eq!(metadata.field_metadata[1].n_values, 1);
+}
+
+#[test]
+fn test_repeated_header_rows() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/concatenated.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(metadata.repeated_header_rows, vec![6, 11]);
+    // the repeats of the header row do not turn the typed fields into text
+    assert_eq!(
+        metadata.types,
+        vec![Type::Unsigned, Type::Float, Type::Date, Type::Text]
+    );
+    assert_eq!(metadata.field_metadata[0].n_values, 12);
+}
+
+#[test]
+fn test_repeated_top_row_without_header() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/headerless-repeat.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert!(!metadata.dialect.header.has_header_row);
+    // the repeat of the top row is a data row like the others
+    assert!(metadata.repeated_header_rows.is_empty());
+    assert_eq!(metadata.types, vec![Type::Float, Type::Float]);
+    assert_eq!(metadata.field_metadata[0].n_values, 5);
+    assert!(metadata.candidate_keys.is_empty());
+    assert!(metadata.sort_orders.is_empty());
+}
+
+#[test]
+fn test_type_annotations() {
+    let data_filepath = Path::new(file!())
+        .parent()
+        .unwrap()
+        .join("data/annotated-headers.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    assert_eq!(
+        metadata.fields,
+        vec![
+            "id",
+            "name",
+            "age",
+            "score",
+            "active",
+            "joined",
+            "tags",
+            "notes (misc)",
+            "customer (ID)",
+            "record (type)"
+        ]
+    );
+    // annotations win over the types inferred from the values (`age` only has positive values,
+    // and `score` only whole numbers)
+    assert_eq!(
+        metadata.types,
+        vec![
+            Type::Text,
+            Type::Text,
+            Type::Signed,
+            Type::Float,
+            Type::Boolean,
+            Type::Date,
+            Type::Text,
+            Type::Text,
+            Type::Unsigned,
+            Type::Text
+        ]
+    );
+    let annotations: Vec<_> = metadata
+        .field_metadata
+        .iter()
+        .map(|field| field.type_annotation.as_deref())
+        .collect();
+    assert_eq!(
+        annotations,
+        vec![
+            Some("ID"),
+            Some("string"),
+            Some("int"),
+            Some("float"),
+            Some("boolean"),
+            Some("date"),
+            Some("string[]"),
+            None,
+            // Neo4j's names are only annotations after a colon
+            None,
+            None
+        ]
+    );
+}
+
+#[test]
+fn test_header_units() {
+    let data_filepath = Path::new(file!()).parent().unwrap().join("data/units.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    let units: Vec<_> = metadata
+        .field_metadata
+        .iter()
+        .map(|field| {
+            field.unit.as_ref().map(|unit| {
+                (
+                    unit.base_name.as_str(),
+                    unit.unit.as_str(),
+                    unit.symbol.as_deref(),
+                    unit.kind,
+                )
+            })
+        })
+        .collect();
+    assert_eq!(
+        units,
+        vec![
+            None,
+            Some(("Weight", "kg", Some("kg"), Some(UnitKind::Mass))),
+            Some((
+                "Temp",
+                "\u{b0}C",
+                Some("\u{b0}C"),
+                Some(UnitKind::Temperature)
+            )),
+            Some(("GDP", "current US$", Some("USD"), Some(UnitKind::Currency))),
+            // a year is not a unit
+            None,
+            Some(("distance", "km", Some("km"), Some(UnitKind::Length))),
+            // units that are not recognized are only taken as such in numeric fields
+            Some(("Rainfall", "mm/yr", None, None)),
+            None,
+            Some(("Height", "m", Some("m"), Some(UnitKind::Length))),
+            // neither are single letters in text fields
+            None,
+            None
+        ]
+    );
+}
+
+#[test]
+fn test_index_column() {
+    let data_dir = Path::new(file!()).parent().unwrap().join("data");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(data_dir.join("pandas-index.csv"))
+        .unwrap();
+    assert!(metadata.has_index_column);
+    assert_eq!(metadata.types[0], Type::Unsigned);
+
+    // the values of an index column are strictly increasing
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(data_dir.join("shuffled-index.csv"))
+        .unwrap();
+    assert_eq!(metadata.fields[0], "Unnamed: 0");
+    assert!(!metadata.has_index_column);
+
+    // an increasing integer field with a name of its own is not an index column
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(data_dir.join("concatenated.csv"))
+        .unwrap();
+    assert!(!metadata.has_index_column);
+}
+
+#[test]
+fn test_candidate_keys_and_sort_orders() {
+    let data_filepath = Path::new(file!()).parent().unwrap().join("data/orders.csv");
+    let metadata = Sniffer::new()
+        .sample_size(SampleSize::All)
+        .sniff_path(&data_filepath)
+        .unwrap();
+    // `seq`, `shipped` and `weight` are unique by themselves, while `order` and `line` only are
+    // together
+    assert_eq!(
+        metadata.candidate_keys,
+        vec![
+            vec![2],
+            vec![6],
+            vec![7],
+            vec![0, 1],
+            vec![0, 4],
+            vec![0, 5],
+            vec![1, 3],
+            vec![1, 5],
+            vec![3, 4],
+            vec![3, 5],
+            vec![4, 5]
+        ]
+    );
+    // `seq` and `weight` are compared as numbers (`10` comes after `9`), and the ISO 8601 dates
+    // of `date` as strings, but the month-first dates of `shipped` are not (as strings, they
+    // would look ascending while they go back in time)
+    assert_eq!(metadata.types[6], Type::Date);
+    assert_eq!(
+        metadata.sort_orders,
+        vec![
+            SortOrder {
+                field: 0,
+                direction: SortDirection::Ascending
+            },
+            SortOrder {
+                field: 2,
+                direction: SortDirection::Ascending
+            },
+            SortOrder {
+                field: 3,
+                direction: SortDirection::Ascending
+            },
+            SortOrder {
+                field: 5,
+                direction: SortDirection::Descending
+            },
+            SortOrder {
+                field: 7,
+                direction: SortDirection::Ascending
+            }
+        ]
+    );
+}
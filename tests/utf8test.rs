@@ -4,7 +4,20 @@ extern crate qsv_sniffer;
 use std::path::Path;
 
 use qsv_sniffer::metadata::*;
-use qsv_sniffer::{DatePreference, SampleSize, Sniffer, Type};
+use qsv_sniffer::{DatePreference, SampleSize, Sniffer, SortDirection, SortOrder, Type};
+
+// The field metadata of `metadata`, taken out so that the rest can be compared as a whole.
+fn take_field_metadata(metadata: &mut Metadata) -> Vec<FieldMetadata> {
+    std::mem::take(&mut metadata.field_metadata)
+}
+
+// The numbers of non-empty and empty values of each field.
+fn value_counts(field_metadata: &[FieldMetadata]) -> Vec<(usize, usize)> {
+    field_metadata
+        .iter()
+        .map(|field| (field.n_values, field.n_empty))
+        .collect()
+}
 
 #[test]
 fn test_utf8() {
@@ -12,10 +25,11 @@ fn test_utf8() {
         .parent()
         .unwrap()
         .join("data/test-utf8.csv");
-    let metadata = Sniffer::new()
+    let mut metadata = Sniffer::new()
         .sample_size(SampleSize::All)
         .sniff_path(data_filepath)
         .unwrap();
+    let field_metadata = take_field_metadata(&mut metadata);
     assert_eq!(
         metadata,
         Metadata {
@@ -44,6 +58,24 @@ fn test_utf8() {
                 "NUM.DDE".to_string(),
                 "NUM.RE".to_string()
             ],
+            normalized_fields: vec![
+                "dia_desemb".to_string(),
+                "cod_subitem_ncm".to_string(),
+                "vmle_dolar_bal_exp".to_string(),
+                "peso_liq_merc_bal_exp".to_string(),
+                "cod_impdr_expdr".to_string(),
+                "nome_impdr_expdr".to_string(),
+                "pais_origem_destino".to_string(),
+                "ua_local_desbq_embq".to_string(),
+                "nome_importador_estrangeiro".to_string(),
+                "num_dde".to_string(),
+                "num_re".to_string()
+            ],
+            header_warnings: vec![],
+            repeated_header_rows: vec![],
+            has_index_column: false,
+            candidate_keys: vec![vec![2], vec![3], vec![10]],
+            sort_orders: vec![],
             types: vec![
                 Type::Text,
                 Type::Unsigned,
@@ -56,9 +88,26 @@ fn test_utf8() {
                 Type::Text,
                 Type::Text,
                 Type::Text
-            ]
+            ],
+            field_metadata: vec![],
         }
     );
+    assert_eq!(
+        value_counts(&field_metadata),
+        vec![
+            (9, 0),
+            (9, 0),
+            (9, 0),
+            (9, 0),
+            (9, 0),
+            (9, 0),
+            (9, 0),
+            (9, 0),
+            (9, 0),
+            (9, 0),
+            (9, 0)
+        ]
+    );
 }
 
 #[test]
@@ -67,10 +116,11 @@ fn test_flexible_again() {
         .parent()
         .unwrap()
         .join("data/gotriangle-routes-cary-ch-duke-durham-raleigh-wofline.csv");
-    let metadata = Sniffer::new()
+    let mut metadata = Sniffer::new()
         .sample_size(SampleSize::All)
         .sniff_path(data_filepath)
         .unwrap();
+    let field_metadata = take_field_metadata(&mut metadata);
     assert_eq!(
         metadata,
         Metadata {
@@ -95,6 +145,20 @@ fn test_flexible_again() {
                 "min_headway_minutes".to_string(),
                 "route_url".to_string()
             ],
+            normalized_fields: vec![
+                "municipality".to_string(),
+                "agency_id".to_string(),
+                "route_id".to_string(),
+                "route_short_name".to_string(),
+                "route_long_name".to_string(),
+                "min_headway_minutes".to_string(),
+                "route_url".to_string()
+            ],
+            header_warnings: vec![],
+            repeated_header_rows: vec![],
+            has_index_column: false,
+            candidate_keys: vec![vec![2], vec![3, 4]],
+            sort_orders: vec![],
             types: vec![
                 Type::Text,
                 Type::Unsigned,
@@ -103,9 +167,22 @@ fn test_flexible_again() {
                 Type::Text,
                 Type::Unsigned,
                 Type::Text
-            ]
+            ],
+            field_metadata: vec![],
         }
     );
+    assert_eq!(
+        value_counts(&field_metadata),
+        vec![
+            (131, 0),
+            (131, 0),
+            (131, 0),
+            (131, 0),
+            (131, 0),
+            (130, 1),
+            (120, 0)
+        ]
+    );
 }
 
 #[test]
@@ -114,11 +191,12 @@ fn test_date_sniffing_dmy() {
         .parent()
         .unwrap()
         .join("data/dmy-test.csv");
-    let metadata = Sniffer::new()
+    let mut metadata = Sniffer::new()
         .sample_size(SampleSize::All)
         .date_preference(DatePreference::DmyFormat)
         .sniff_path(data_filepath)
         .unwrap();
+    let field_metadata = take_field_metadata(&mut metadata);
     assert_eq!(
         metadata,
         Metadata {
@@ -139,7 +217,36 @@ fn test_date_sniffing_dmy() {
                 "letter".to_string(),
                 "number".to_string(),
             ],
-            types: vec![Type::Date, Type::Text, Type::Unsigned,]
+            normalized_fields: vec![
+                "starttime".to_string(),
+                "letter".to_string(),
+                "number".to_string()
+            ],
+            header_warnings: vec![],
+            repeated_header_rows: vec![],
+            has_index_column: false,
+            candidate_keys: vec![vec![0], vec![1], vec![2]],
+            sort_orders: vec![
+                SortOrder {
+                    field: 1,
+                    direction: SortDirection::Ascending
+                },
+                SortOrder {
+                    field: 2,
+                    direction: SortDirection::Ascending
+                }
+            ],
+            types: vec![Type::Date, Type::Text, Type::Unsigned,],
+            field_metadata: vec![],
         }
     );
+    assert_eq!(
+        value_counts(&field_metadata),
+        vec![(26, 0), (26, 0), (26, 0)]
+    );
+    let date_formats: Vec<Option<&str>> = field_metadata
+        .iter()
+        .map(|field| field.date_format.as_deref())
+        .collect();
+    assert_eq!(date_formats, vec![Some("%d/%m/%Y"), None, None]);
 }